@@ -0,0 +1,59 @@
+use crate::level::Node;
+
+pub const MAX_ASCENSION: u8 = 5;
+
+/// Difficulty tier of a run. Every tier keeps the modifiers of the tiers
+/// below it and adds one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Ascension(u8);
+
+impl Ascension {
+    pub fn new(tier: u8) -> Self {
+        Self(tier.min(MAX_ASCENSION))
+    }
+
+    pub fn tier(self) -> u8 {
+        self.0
+    }
+
+    pub fn next(self) -> Self {
+        Self::new(self.0 + 1)
+    }
+
+    /// Target score of a level, in percent of the normal one.
+    pub fn target_percent(self, node: Node) -> i32 {
+        let mut percent = 100;
+        if self.0 >= 1 {
+            percent += 25;
+        }
+        if self.0 >= 5 && node == Node::Boss {
+            percent += 50;
+        }
+        percent
+    }
+
+    pub fn removed_starting_balls(self) -> usize {
+        if self.0 >= 2 { 1 } else { 0 }
+    }
+
+    /// Bucket speed, in percent of the normal one.
+    pub fn bucket_speed_percent(self) -> i32 {
+        if self.0 >= 3 { 150 } else { 100 }
+    }
+
+    pub fn removed_yellow_pegs(self) -> i32 {
+        if self.0 >= 4 { 2 } else { 0 }
+    }
+
+    /// The modifier this tier adds on top of the previous ones.
+    pub fn description(self) -> &'static str {
+        match self.0 {
+            0 => "The usual run.",
+            1 => "Higher targets.",
+            2 => "One starting ball fewer.",
+            3 => "Faster bucket.",
+            4 => "Fewer {yellow}yellow{white} pegs.",
+            _ => "Stronger bosses.",
+        }
+    }
+}
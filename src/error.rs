@@ -2,8 +2,9 @@
 pub enum Error {
     Convertion,
     IndexTooBig,
-    CellFull,
     NoBalls,
-    TooManyNeighbors,
     NoLastState,
+    InventoryFull,
+    NotEnoughCoins,
+    Storage,
 }
@@ -0,0 +1,149 @@
+use heapless::Vec;
+
+use crate::ascension::Ascension;
+use crate::number::Number;
+use crate::save::{BallKind, MAX_INVENTORY, OwnedBall, Save};
+
+/// Runs kept, the newest first.
+pub const MAX_RUNS: usize = 5;
+
+const MAGIC: [u8; 4] = *b"RUN1";
+const RECORD_SIZE: usize = 16 + MAX_INVENTORY;
+// Each bag slot packs the kind into the low nibble.
+const _: () = assert!(BallKind::ALL.len() <= 16);
+pub const SIZE: usize = 4 + RECORD_SIZE * MAX_RUNS;
+
+/// How a run ended.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ending {
+    Won,
+    OutOfBalls,
+}
+
+impl Ending {
+    pub fn description(self) -> &'static str {
+        match self {
+            Ending::Won => "{yellow}Won{white}",
+            Ending::OutOfBalls => "{red}Out of balls{white}",
+        }
+    }
+}
+
+/// A finished run, as shown on the stats screen.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunRecord {
+    pub seed: u32,
+    pub ascension: Ascension,
+    pub depth: i32,
+    pub ending: Ending,
+    pub best_shot: Number,
+    pub pegs_hit: u32,
+    pub inventory: Vec<OwnedBall, MAX_INVENTORY>,
+}
+
+impl RunRecord {
+    pub fn new(save: &Save, ending: Ending) -> Self {
+        Self {
+            seed: save.seed(),
+            ascension: save.ascension(),
+            depth: save.level(),
+            ending,
+            best_shot: save.best_shot(),
+            pegs_hit: save.pegs_hit(),
+            inventory: save.inventory().clone(),
+        }
+    }
+
+    fn to_bytes(&self) -> [u8; RECORD_SIZE] {
+        let mut bytes = [0; RECORD_SIZE];
+        bytes[..4].copy_from_slice(&self.seed.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.best_shot.get().to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.pegs_hit.to_le_bytes());
+        bytes[12] = self.depth.clamp(0, u8::MAX as i32) as u8;
+        bytes[13] = self.ending as u8;
+        bytes[14] = self.ascension.tier();
+        // A ball is its kind and tier in one byte, zero is an empty slot.
+        for (byte, ball) in bytes[16..].iter_mut().zip(&self.inventory) {
+            *byte = ball.kind as u8 | ball.tier << 4;
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let word = |offset: usize| {
+            let mut word = [0; 4];
+            word.copy_from_slice(&bytes[offset..offset + 4]);
+            word
+        };
+
+        Self {
+            seed: u32::from_le_bytes(word(0)),
+            ascension: Ascension::new(bytes[14]),
+            depth: bytes[12] as i32,
+            ending: if bytes[13] == Ending::Won as u8 {
+                Ending::Won
+            } else {
+                Ending::OutOfBalls
+            },
+            best_shot: i32::from_le_bytes(word(4)).into(),
+            pegs_hit: u32::from_le_bytes(word(8)),
+            inventory: bytes[16..]
+                .iter()
+                .take_while(|&&byte| byte != 0)
+                .filter_map(|&byte| {
+                    let kind = *BallKind::ALL.get((byte & 0xf) as usize)?;
+                    Some(OwnedBall {
+                        kind,
+                        tier: (byte >> 4).clamp(1, OwnedBall::MAX_TIER),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The last few runs, stored in SRAM right after `Progress`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct History {
+    runs: Vec<RunRecord, MAX_RUNS>,
+}
+
+impl History {
+    /// Newest first.
+    pub fn runs(&self) -> &[RunRecord] {
+        &self.runs
+    }
+
+    pub fn push(&mut self, record: RunRecord) {
+        if self.runs.is_full() {
+            self.runs.pop();
+        }
+        let _ = self.runs.insert(0, record);
+    }
+
+    pub fn to_bytes(&self) -> [u8; SIZE] {
+        let mut bytes = [0; SIZE];
+        bytes[..4].copy_from_slice(&MAGIC);
+        for (chunk, run) in
+            bytes[4..].chunks_exact_mut(RECORD_SIZE).zip(&self.runs)
+        {
+            chunk.copy_from_slice(&run.to_bytes());
+        }
+        bytes
+    }
+
+    /// Empty when SRAM holds no history.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        if bytes[..4] != MAGIC {
+            return Self::default();
+        }
+
+        Self {
+            runs: bytes[4..]
+                .chunks_exact(RECORD_SIZE)
+                .take_while(|chunk| chunk[12] != 0)
+                .map(RunRecord::from_bytes)
+                .collect(),
+        }
+    }
+}
@@ -1,4 +1,47 @@
+use crate::ascension::Ascension;
 use crate::peg::Kind;
+use crate::physics::Motion;
+use agb::fixnum::vec2;
+use heapless::Vec;
+
+const ELITE_EVERY: i32 = 3;
+const BOSS_EVERY: i32 = 5;
+const EVENT_EVERY: i32 = 4;
+/// Winning this level wins the run.
+pub const FINAL_DEPTH: i32 = 15;
+const MAX_MODIFIERS: usize = 4;
+
+/// What kind of fight a level is, which decides how generous its drop is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Node {
+    Battle,
+    Elite,
+    Boss,
+}
+
+impl Node {
+    pub fn at_depth(depth: i32) -> Self {
+        if depth % BOSS_EVERY == 0 {
+            Node::Boss
+        } else if depth % ELITE_EVERY == 0 {
+            Node::Elite
+        } else {
+            Node::Battle
+        }
+    }
+}
+
+/// Rules specific to a level. They react to scoring events like relics do.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LevelModifier {
+    /// Bonus base every time the ball bounces off a side wall.
+    PaddedWalls(i32),
+}
+
+/// Whether a story event comes before the fight at `depth`.
+pub fn has_event(depth: i32) -> bool {
+    depth % EVENT_EVERY == 2
+}
 
 pub struct Level {
     target_score: i32,
@@ -6,16 +49,65 @@ pub struct Level {
     red_qty: i32,
     yellow_qty: i32,
     green_qty: i32,
+    bucket_speed_percent: i32,
+    modifiers: Vec<LevelModifier, MAX_MODIFIERS>,
+    /// How pegs of each kind move, indexed by `Kind`.
+    motions: [Motion; 4],
+    /// Largest random kick given to drifting pegs off the walls.
+    peg_jitter: i32,
+}
+
+/// Regular fights keep the drifting boards, the bigger ones get more
+/// structure.
+fn motions_at(node: Node) -> [Motion; 4] {
+    match node {
+        Node::Battle => [Motion::Drifting; 4],
+        Node::Elite => [
+            Motion::Spring { stiffness: 20 },
+            Motion::Orbiting {
+                radius: 6,
+                period: 180,
+            },
+            Motion::Spring { stiffness: 20 },
+            Motion::Drifting,
+        ],
+        Node::Boss => [
+            Motion::Static,
+            Motion::Oscillating {
+                offset: vec2(24, 0),
+                period: 240,
+            },
+            Motion::Orbiting {
+                radius: 8,
+                period: 120,
+            },
+            Motion::Drifting,
+        ],
+    }
 }
 
 impl Level {
-    pub fn new_test_level(level: i32) -> Self {
+    pub fn new_test_level(level: i32, ascension: Ascension) -> Self {
+        let node = Node::at_depth(level);
+        let mut modifiers = Vec::new();
+        if node != Node::Battle {
+            let _ = modifiers.push(LevelModifier::PaddedWalls(2));
+        }
+
         Self {
-            target_score: 50 * level,
+            target_score: 50 * level * ascension.target_percent(node) / 100,
             blue_qty: 20,
             red_qty: 10,
-            yellow_qty: 5,
+            yellow_qty: 5 - ascension.removed_yellow_pegs(),
             green_qty: 2,
+            bucket_speed_percent: ascension.bucket_speed_percent(),
+            modifiers,
+            motions: motions_at(node),
+            peg_jitter: match node {
+                Node::Battle => 5,
+                Node::Elite => 3,
+                Node::Boss => 2,
+            },
         }
     }
 
@@ -23,6 +115,22 @@ impl Level {
         self.target_score
     }
 
+    pub fn bucket_speed_percent(&self) -> i32 {
+        self.bucket_speed_percent
+    }
+
+    pub fn modifiers(&self) -> &[LevelModifier] {
+        &self.modifiers
+    }
+
+    pub fn motion(&self, kind: Kind) -> Motion {
+        self.motions[kind as usize]
+    }
+
+    pub fn peg_jitter(&self) -> i32 {
+        self.peg_jitter
+    }
+
     pub fn peg_count<const N: usize>(&self) -> [Option<Kind>; N] {
         let mut result = [None; N];
         let mut idx = 0;
@@ -0,0 +1,167 @@
+use agb::rng::RandomNumberGenerator;
+use heapless::Vec;
+
+use crate::level::Node;
+use crate::save::{BallKind, MAX_RELICS, RelicKind};
+
+/// Misses in a row after which only rare or better balls are offered.
+const PITY_GUARANTEE: u32 = 4;
+/// Extra rare weight for every bench without a rare ball.
+const PITY_STEP: u32 = 5;
+const MIN_COMMON_WEIGHT: u32 = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Legendary,
+}
+
+impl Rarity {
+    const ALL: [Rarity; 4] = [
+        Rarity::Common,
+        Rarity::Uncommon,
+        Rarity::Rare,
+        Rarity::Legendary,
+    ];
+
+    /// Colour name understood by the text box markup.
+    pub fn colour(self) -> &'static str {
+        match self {
+            Rarity::Common => "white",
+            Rarity::Uncommon => "green",
+            Rarity::Rare => "blue",
+            Rarity::Legendary => "yellow",
+        }
+    }
+
+    fn weights(depth: i32, node: Node, pity: u32) -> [u32; 4] {
+        let depth = depth.max(1) as u32 - 1;
+
+        let mut common =
+            (60u32.saturating_sub(3 * depth)).max(MIN_COMMON_WEIGHT);
+        let mut uncommon = 28 + 2 * depth;
+        let mut rare = 10 + depth + PITY_STEP * pity;
+        let mut legendary = 2 + depth / 3;
+
+        match node {
+            Node::Battle => {}
+            Node::Elite => {
+                rare *= 2;
+                legendary *= 2;
+            }
+            Node::Boss => {
+                common = 0;
+                rare *= 3;
+                legendary *= 3;
+            }
+        }
+
+        if pity >= PITY_GUARANTEE {
+            common = 0;
+            uncommon = 0;
+        }
+
+        [common, uncommon, rare, legendary]
+    }
+}
+
+/// Uniform value in `0..bound`, rejecting the values that would bias a plain
+/// modulo.
+pub fn below(rng: &mut RandomNumberGenerator, bound: u32) -> u32 {
+    let zone = u32::MAX - u32::MAX % bound;
+    loop {
+        let value = rng.next_i32() as u32;
+        if value < zone {
+            return value % bound;
+        }
+    }
+}
+
+fn left<'a>(
+    pool: &'a [BallKind],
+    bench: &'a [BallKind],
+    rarity: Rarity,
+) -> impl Iterator<Item = BallKind> + 'a {
+    pool.iter()
+        .copied()
+        .filter(move |ball| ball.rarity() == rarity && !bench.contains(ball))
+}
+
+/// Picks up to `N` different balls from `pool`. A rarity is drawn first among those that
+/// still have balls left, then a ball of that rarity. `pity` counts benches
+/// without a rare ball and is updated for the next drop.
+pub fn roll_bench<const N: usize>(
+    rng: &mut RandomNumberGenerator,
+    depth: i32,
+    node: Node,
+    pity: &mut u32,
+    pool: &[BallKind],
+) -> Vec<BallKind, N> {
+    let mut bench: Vec<BallKind, N> = Vec::new();
+    let weights = Rarity::weights(depth, node, *pity);
+
+    while !bench.is_full() {
+        let counts = Rarity::ALL.map(|r| left(pool, &bench, r).count() as u32);
+        let mut chances = [0; 4];
+        for i in 0..chances.len() {
+            chances[i] = if counts[i] > 0 { weights[i] } else { 0 };
+        }
+        if chances.iter().all(|&w| w == 0) {
+            // Every weighted rarity ran dry: fall back to anything left
+            chances = counts.map(|count| count.min(1));
+        }
+
+        let total: u32 = chances.iter().sum();
+        if total == 0 {
+            break;
+        }
+
+        let mut roll = below(rng, total);
+        let mut rarity = Rarity::Common;
+        for (r, chance) in Rarity::ALL.into_iter().zip(chances) {
+            if roll < chance {
+                rarity = r;
+                break;
+            }
+            roll -= chance;
+        }
+
+        let index = below(rng, counts[rarity as usize]) as usize;
+        let ball = left(pool, &bench, rarity).nth(index);
+        if let Some(ball) = ball {
+            let _ = bench.push(ball);
+        }
+    }
+
+    if bench.iter().any(|ball| ball.rarity() >= Rarity::Rare) {
+        *pity = 0;
+    } else {
+        *pity += 1;
+    }
+
+    bench
+}
+
+/// A relic the player does not hold yet, if there is room for one.
+pub fn new_relic(
+    rng: &mut RandomNumberGenerator,
+    held: &Vec<RelicKind, MAX_RELICS>,
+) -> Option<RelicKind> {
+    if held.is_full() {
+        return None;
+    }
+
+    let missing = || {
+        RelicKind::ALL
+            .into_iter()
+            .filter(|relic| !held.contains(relic))
+    };
+    let count = missing().count() as u32;
+    if count == 0 {
+        return None;
+    }
+
+    missing().nth(below(rng, count) as usize)
+}
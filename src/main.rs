@@ -9,11 +9,16 @@ use agb::fixnum::{FixedNum, Vector2D};
 
 extern crate alloc;
 
+mod ascension;
 mod bench;
 mod error;
+mod history;
 mod level;
+mod loot;
+mod number;
 mod peg;
 mod physics;
+mod progress;
 mod reset;
 mod save;
 mod scenes;
@@ -23,10 +28,13 @@ pub type Fixed = FixedNum<8>;
 pub type Coordinates = Vector2D<Fixed>;
 pub type Force = Vector2D<Fixed>;
 
-#[cfg(not(test))]
-#[agb::entry]
-fn entry(gba: agb::Gba) -> ! {
+// Test builds have their own entry, but still compile the game loop so that
+// everything it uses counts as used.
+#[cfg_attr(not(test), agb::entry)]
+#[cfg_attr(test, allow(dead_code))]
+fn entry(mut gba: agb::Gba) -> ! {
     agb::println!("Starting game...");
+    gba.save.init_sram();
     let save = save::Save::new();
 
     match scenes::main(gba, save) {
@@ -68,22 +76,26 @@ mod tests {
     #[test_case]
     fn test_frame_commit(gba: &mut Gba) {
         let mut gfx = gba.graphics.get();
-        let mut frame = gfx.frame();
+        let frame = gfx.frame();
         frame.commit();
     }
 
     #[test_case]
     fn test_physics_constants(_gba: &mut Gba) {
-        use crate::scenes::game::ball::RADIUS as BALL_RADIUS;
-        use crate::scenes::game::peg::RADIUS as PEG_RADIUS;
+        use crate::scenes::game::config::GameConfig;
+
+        const BALL_RADIUS: i32 = GameConfig::BALL_RADIUS;
+        const PEG_RADIUS: i32 = GameConfig::PEG_RADIUS;
 
         agb::println!("Testing physics constants");
 
         // Test that physics constants are reasonable
-        assert!(PEG_RADIUS > 0);
-        assert!(BALL_RADIUS > 0);
-        assert!(PEG_RADIUS < 20); // Reasonable size for GBA screen
-        assert!(BALL_RADIUS < 20); // Reasonable size for GBA screen
+        const {
+            assert!(PEG_RADIUS > 0);
+            assert!(BALL_RADIUS > 0);
+            assert!(PEG_RADIUS < 20); // Reasonable size for GBA screen
+            assert!(BALL_RADIUS < 20); // Reasonable size for GBA screen
+        }
 
         agb::println!(
             "Peg radius: {}, Ball radius: {}",
@@ -137,4 +149,187 @@ mod tests {
             save.inventory().len()
         );
     }
+
+    #[test_case]
+    fn test_drop_bench(_gba: &mut Gba) {
+        use crate::level::Node;
+        use crate::loot::{self, Rarity};
+        use crate::save::BallKind;
+        use agb::rng::RandomNumberGenerator;
+
+        let mut rng = RandomNumberGenerator::new();
+        let mut pity = 0;
+
+        for depth in 1..20 {
+            let bench = loot::roll_bench::<3>(
+                &mut rng,
+                depth,
+                Node::at_depth(depth),
+                &mut pity,
+                &BallKind::DROPPABLES,
+            );
+            assert_eq!(bench.len(), 3);
+            for (i, ball) in bench.iter().enumerate() {
+                assert!(!bench[i + 1..].contains(ball));
+            }
+        }
+
+        pity = 10;
+        let bench = loot::roll_bench::<1>(
+            &mut rng,
+            1,
+            Node::Battle,
+            &mut pity,
+            &BallKind::DROPPABLES,
+        );
+        assert!(bench[0].rarity() >= Rarity::Rare);
+        assert_eq!(pity, 0);
+    }
+
+    #[test_case]
+    fn test_number_saturates(_gba: &mut Gba) {
+        use crate::number::Number;
+
+        let max = Number::from(i32::MAX);
+        let min = Number::from(i32::MIN);
+
+        assert_eq!(max + Number::from(1), max);
+        assert_eq!(min - Number::from(1), min);
+        assert_eq!(max * Number::from(2), max);
+        assert_eq!(max * Number::from(-2), min);
+        assert_eq!(-min, max);
+        assert_eq!(Number::from(46_341) * Number::from(46_341), max);
+        assert_eq!(
+            Number::from(46_340) * Number::from(46_340),
+            Number::from(2_147_395_600),
+        );
+    }
+
+    #[test_case]
+    fn test_number_compact(_gba: &mut Gba) {
+        use crate::number::{MAX_TEXT, MIN_COMPACT_WIDTH, Number};
+
+        assert_eq!(Number::from(0).compact(5), "0");
+        assert_eq!(Number::from(99_999).compact(5), "99999");
+        assert_eq!(Number::from(100_000).compact(5), "1.0e5");
+        assert_eq!(Number::from(123_456).compact(6), "123456");
+        assert_eq!(Number::from(1_234_567).compact(5), "1.2e6");
+        assert_eq!(Number::from(-1_234_567).compact(5), "-1e6");
+        assert_eq!(Number::from(-1_234_567).compact(6), "-1.2e6");
+        assert_eq!(Number::from(i32::MAX).compact(5), "2.1e9");
+        assert_eq!(Number::from(i32::MIN).compact(5), "-2e9");
+        assert_eq!(Number::from(12_345).compact(1), "1e4");
+
+        for value in [i32::MIN, -99_999, -1_000, 99_999, i32::MAX] {
+            for width in MIN_COMPACT_WIDTH..=MAX_TEXT {
+                assert!(Number::from(value).compact(width).len() <= width);
+            }
+        }
+    }
+
+    #[test_case]
+    fn test_number_glyphs(_gba: &mut Gba) {
+        use crate::number::{
+            Glyph, MAX_TEXT, MIN_COMPACT_WIDTH, Number, Sign, glyphs,
+        };
+        use Glyph::{Digit, Exponent, Minus, Plus, Point};
+
+        let split =
+            |value: i32, width, sign| glyphs(Number::from(value), width, sign);
+
+        assert_eq!(split(0, 5, Sign::NegativeOnly), [Digit(0)]);
+        assert_eq!(split(0, 5, Sign::Always), [Digit(0)]);
+        assert_eq!(split(7, 5, Sign::Always), [Plus, Digit(7)]);
+        assert_eq!(split(20, 5, Sign::NegativeOnly), [Digit(2), Digit(0)]);
+        assert_eq!(
+            split(-305, 5, Sign::NegativeOnly),
+            [Minus, Digit(3), Digit(0), Digit(5)]
+        );
+        assert_eq!(
+            split(1_234_567, 5, Sign::NegativeOnly),
+            [Digit(1), Point, Digit(2), Exponent, Digit(6)]
+        );
+        assert_eq!(
+            split(-99_999, 5, Sign::NegativeOnly),
+            [Minus, Digit(9), Exponent, Digit(4)]
+        );
+        assert_eq!(
+            split(99_999, 6, Sign::Always),
+            [Plus, Digit(9), Digit(9), Digit(9), Digit(9), Digit(9)]
+        );
+        assert_eq!(
+            split(1_000, 4, Sign::Always),
+            [Digit(1), Digit(0), Digit(0), Digit(0)]
+        );
+        assert_eq!(
+            split(100_000, 5, Sign::Always),
+            [Plus, Digit(1), Exponent, Digit(5)]
+        );
+        for value in [i32::MIN, -99_999, 99_999, i32::MAX] {
+            for width in MIN_COMPACT_WIDTH..=MAX_TEXT {
+                for sign in [Sign::NegativeOnly, Sign::Always] {
+                    assert!(split(value, width, sign).len() <= width);
+                }
+            }
+        }
+        assert_eq!(Glyph::Plus.frame(), 13);
+    }
+
+    #[test_case]
+    fn test_ascension_stacks(_gba: &mut Gba) {
+        use crate::ascension::{Ascension, MAX_ASCENSION};
+        use crate::level::Node;
+        use crate::save::{Loadout, Save};
+
+        let base = Ascension::default();
+        let top = Ascension::new(MAX_ASCENSION + 3);
+        assert_eq!(top.tier(), MAX_ASCENSION);
+        assert_eq!(top.next(), top);
+
+        assert_eq!(base.target_percent(Node::Boss), 100);
+        assert!(
+            top.target_percent(Node::Boss) > top.target_percent(Node::Elite)
+        );
+        assert!(top.target_percent(Node::Battle) > 100);
+        assert!(top.bucket_speed_percent() > base.bucket_speed_percent());
+        assert!(top.removed_yellow_pegs() > 0);
+
+        let save = Save::new_run(top, Loadout::Classic, &[], 0);
+        assert_eq!(Save::new().inventory().len(), 5);
+        assert_eq!(save.inventory().len(), 4);
+    }
+
+    #[test_case]
+    fn test_history_round_trip(_gba: &mut Gba) {
+        use crate::ascension::Ascension;
+        use crate::history::{Ending, History, MAX_RUNS, RunRecord, SIZE};
+        use crate::save::{BallKind, LevelResult, Loadout, Save};
+
+        let mut save =
+            Save::new_run(Ascension::new(2), Loadout::Grabbers, &[], 42);
+        save.record_result(LevelResult {
+            damage: 100.into(),
+            target: 50,
+            coins: 3,
+            best_shot: 80.into(),
+            pegs_hit: 12,
+        });
+        save.push_ball(BallKind::TheCrown).unwrap();
+
+        let mut history = History::default();
+        for _ in 0..MAX_RUNS {
+            history.push(RunRecord::new(&Save::new(), Ending::OutOfBalls));
+        }
+        history.push(RunRecord::new(&save, Ending::Won));
+        assert_eq!(history.runs().len(), MAX_RUNS);
+
+        let newest = &history.runs()[0];
+        assert_eq!(newest.seed, 42);
+        assert_eq!(newest.pegs_hit, 12);
+        assert_eq!(newest.ending, Ending::Won);
+
+        let loaded = History::from_bytes(&history.to_bytes());
+        assert_eq!(loaded, history);
+        assert!(History::from_bytes(&[0; SIZE]).runs().is_empty());
+    }
 }
@@ -0,0 +1,164 @@
+use core::fmt::{self, Write};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub};
+use heapless::{String, Vec};
+
+/// Longest text a number can print as: sign plus the ten digits of `i32`.
+pub const MAX_TEXT: usize = 11;
+
+/// Narrowest width every number fits in, like "-2e9".
+pub const MIN_COMPACT_WIDTH: usize = 4;
+
+/// Width of numbers formatted with `Display`, enough for "-2.1e9".
+const DISPLAY_WIDTH: usize = 6;
+
+/// Score number. Arithmetic saturates at the `i32` bounds instead of
+/// wrapping, so stacked multipliers cap out rather than turn negative.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Number(i32);
+
+impl Number {
+    pub const ZERO: Self = Self(0);
+
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+
+    pub fn clamp(self, min: i32, max: i32) -> i32 {
+        self.0.clamp(min, max)
+    }
+
+    /// The number as text no wider than `width`, sign included. Digits that
+    /// do not fit switch to an exponent with one decimal of mantissa, or none
+    /// when there is no room for it. Widths below `MIN_COMPACT_WIDTH` are
+    /// treated as `MIN_COMPACT_WIDTH`.
+    pub fn compact(self, width: usize) -> String<MAX_TEXT> {
+        let width = width.max(MIN_COMPACT_WIDTH);
+        let mut text = String::new();
+        let _ = write!(text, "{}", self.0);
+        if text.len() <= width {
+            return text;
+        }
+
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let magnitude = self.0.unsigned_abs();
+        let exponent = magnitude.ilog10();
+        let mantissa = magnitude / 10u32.pow(exponent - 1);
+
+        text.clear();
+        let _ = write!(
+            text,
+            "{sign}{}.{}e{exponent}",
+            mantissa / 10,
+            mantissa % 10
+        );
+        if text.len() > width {
+            text.clear();
+            let _ = write!(text, "{sign}{}e{exponent}", mantissa / 10);
+        }
+        text
+    }
+}
+
+/// When a number widget prints a sign.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sign {
+    NegativeOnly,
+    Always,
+}
+
+/// What a number widget draws, one sprite each.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Glyph {
+    Digit(u8),
+    Point,
+    Exponent,
+    Minus,
+    Plus,
+}
+
+impl Glyph {
+    /// Frame in the digit sprite sheets, which all share this order.
+    pub fn frame(self) -> usize {
+        match self {
+            Glyph::Digit(d) => d as usize,
+            Glyph::Point => 10,
+            Glyph::Exponent => 11,
+            Glyph::Minus => 12,
+            Glyph::Plus => 13,
+        }
+    }
+}
+
+/// Splits `value` into glyphs, most significant first, no more of them than
+/// `width` sign included, with the same minimum as `Number::compact`. A plus
+/// that does not fit is left out.
+pub fn glyphs(value: Number, width: usize, sign: Sign) -> Vec<Glyph, MAX_TEXT> {
+    let plus = sign == Sign::Always && value > Number::ZERO;
+    let text = value.compact(width.saturating_sub(plus as usize));
+    let plus = plus && text.len() < width.max(MIN_COMPACT_WIDTH);
+
+    let mut glyphs = Vec::new();
+    if plus {
+        let _ = glyphs.push(Glyph::Plus);
+    }
+    for c in text.chars() {
+        let glyph = match c {
+            '0'..='9' => Glyph::Digit(c as u8 - b'0'),
+            '.' => Glyph::Point,
+            'e' => Glyph::Exponent,
+            _ => Glyph::Minus,
+        };
+        let _ = glyphs.push(glyph);
+    }
+    glyphs
+}
+
+impl From<i32> for Number {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl Add for Number {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for Number {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Number {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Mul for Number {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0.saturating_mul(rhs.0))
+    }
+}
+
+impl Neg for Number {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.saturating_neg())
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.compact(DISPLAY_WIDTH))
+    }
+}
@@ -0,0 +1,502 @@
+//! Trajectories recorded from `scenario`, one sample per tracked body per
+//! frame. When a physics change is intended, the failing test prints the new
+//! trajectory in this format to replace the old one.
+
+use super::scenario::Sample;
+
+pub const BALL_VS_SINGLE_PEG: &[Sample] = &[
+    [20992, 7692, 0, 800],
+    [20992, 7717, 0, 1600],
+    [20992, 7754, 0, 2400],
+    [20992, 7804, 0, 3200],
+    [20992, 7866, 0, 4000],
+    [20992, 7941, 0, 4800],
+    [20992, 8028, 0, 5600],
+    [20992, 8128, 0, 6400],
+    [20992, 8240, 0, 7200],
+    [20992, 8365, 0, 8000],
+    [20992, 8502, 0, 8800],
+    [20992, 8652, 0, 9600],
+    [20992, 8814, 0, 10400],
+    [20992, 8989, 0, 11200],
+    [20992, 9176, 0, 12000],
+    [20992, 9376, 0, 12800],
+    [20992, 9588, 0, 13600],
+    [20992, 9813, 0, 14400],
+    [20992, 10050, 0, 15200],
+    [20992, 10300, 0, 16000],
+    [20992, 10562, 0, 16800],
+    [20992, 10837, 0, 17600],
+    [20992, 11124, 0, 18400],
+    [20992, 11424, 0, 19200],
+    [20992, 11736, 0, 20000],
+    [20992, 12061, 0, 20800],
+    [20992, 12398, 0, 21600],
+    [20992, 12748, 0, 22400],
+    [20992, 13110, 0, 23200],
+    [20992, 13485, 0, 24000],
+    [21028, 13766, 13658, -17541],
+    [21241, 13504, 13658, -16741],
+    [21454, 13254, 13658, -15941],
+    [21667, 13017, 13658, -15141],
+    [21880, 12792, 13658, -14341],
+    [22093, 12580, 13658, -13541],
+    [22306, 12380, 13658, -12741],
+    [22519, 12193, 13658, -11941],
+    [22732, 12018, 13658, -11141],
+    [22945, 11856, 13658, -10341],
+    [23158, 11706, 13658, -9541],
+    [23371, 11569, 13658, -8741],
+    [23584, 11444, 13658, -7941],
+    [23797, 11332, 13658, -7141],
+    [24010, 11232, 13658, -6341],
+    [24223, 11145, 13658, -5541],
+    [24436, 11070, 13658, -4741],
+    [24649, 11008, 13658, -3941],
+    [24862, 10958, 13658, -3141],
+    [25075, 10921, 13658, -2341],
+    [25288, 10896, 13658, -1541],
+    [25501, 10884, 13658, -741],
+    [25714, 10884, 13658, 59],
+    [25927, 10897, 13658, 859],
+    [26140, 10922, 13658, 1659],
+    [26353, 10960, 13658, 2459],
+    [26566, 11010, 13658, 3259],
+    [26779, 11073, 13658, 4059],
+    [26992, 11148, 13658, 4859],
+    [27205, 11236, 13658, 5659],
+];
+
+pub const CORNER_ESCAPE: &[Sample] = &[
+    [10000, 7440, -15360, -15360],
+    [9760, 7200, -15360, -15360],
+    [9520, 6960, -15360, -15360],
+    [9280, 6720, -15360, -15360],
+    [9040, 6480, -15360, -15360],
+    [8800, 6240, -15360, -15360],
+    [8560, 6000, -15360, -15360],
+    [8320, 5760, -15360, -15360],
+    [8080, 5520, -15360, -15360],
+    [7840, 5280, -15360, -15360],
+    [7600, 5040, -15360, -15360],
+    [7360, 4800, -15360, -15360],
+    [7120, 4560, -15360, -15360],
+    [6880, 4320, -15360, -15360],
+    [6640, 4080, -15360, -15360],
+    [6400, 3840, -15360, -15360],
+    [6160, 3600, -15360, -15360],
+    [5920, 3360, -15360, -15360],
+    [5680, 3120, -15360, -15360],
+    [5440, 2880, -15360, -15360],
+    [5376, 2816, 17116, 17116],
+    [5643, 3083, 17116, 17116],
+    [5910, 3350, 17116, 17116],
+    [6177, 3617, 17116, 17116],
+    [6444, 3884, 17116, 17116],
+    [6711, 4151, 17116, 17116],
+    [6978, 4418, 17116, 17116],
+    [7245, 4685, 17116, 17116],
+    [7512, 4952, 17116, 17116],
+    [7779, 5219, 17116, 17116],
+    [8046, 5486, 17116, 17116],
+    [8313, 5753, 17116, 17116],
+    [8580, 6020, 17116, 17116],
+    [8847, 6287, 17116, 17116],
+    [9114, 6554, 17116, 17116],
+    [9381, 6821, 17116, 17116],
+    [9648, 7088, 17116, 17116],
+    [9915, 7355, 17116, 17116],
+    [10182, 7622, 17116, 17116],
+    [10449, 7889, 17116, 17116],
+    [10716, 8156, 17116, 17116],
+    [10983, 8423, 17116, 17116],
+    [11250, 8690, 17116, 17116],
+    [11517, 8957, 17116, 17116],
+    [11784, 9224, 17116, 17116],
+];
+
+pub const BUCKET_WALL_HITS: &[Sample] = &[
+    [18160, 23012, 15360, -1760],
+    [18400, 22997, 15360, -960],
+    [18640, 22994, 15360, -160],
+    [18880, 23004, 15360, 640],
+    [19120, 23026, 15360, 1440],
+    [19360, 23061, 15360, 2240],
+    [19600, 23108, 15360, 3040],
+    [19840, 23168, 15360, 3840],
+    [20080, 23240, 15360, 4640],
+    [20320, 23325, 15360, 5440],
+    [20560, 23422, 15360, 6240],
+    [20800, 23532, 15360, 7040],
+    [21040, 23654, 15360, 7840],
+    [21280, 23789, 15360, 8640],
+    [21520, 23936, 15360, 9440],
+    [21760, 24096, 15360, 10240],
+    [22000, 24268, 15360, 11040],
+    [22240, 24453, 15360, 11840],
+    [22480, 24650, 15360, 12640],
+    [22720, 24860, 15360, 13440],
+    [22960, 25082, 15360, 14240],
+    [23200, 25317, 15360, 15040],
+    [23440, 25564, 15360, 15840],
+    [23680, 25824, 15360, 16640],
+    [23920, 26096, 15360, 17440],
+    [24160, 26381, 15360, 18240],
+    [24400, 26678, 15360, 19040],
+    [24640, 26988, 15360, 19840],
+    [24880, 27310, 15360, 20640],
+    [24904, 27412, -16518, -12820],
+    [24645, 27224, -16518, -12020],
+    [24386, 27048, -16518, -11220],
+    [24127, 26885, -16518, -10420],
+    [23868, 26734, -16518, -9620],
+    [23609, 26596, -16518, -8820],
+    [23350, 26470, -16518, -8020],
+    [23091, 26357, -16518, -7220],
+    [22832, 26256, -16518, -6420],
+    [22573, 26168, -16518, -5620],
+    [22314, 26092, -16518, -4820],
+    [22055, 26029, -16518, -4020],
+    [21796, 25978, -16518, -3220],
+    [21537, 25940, -16518, -2420],
+    [21278, 25914, -16518, -1620],
+    [21019, 25901, -16518, -820],
+    [20760, 25900, -16518, -20],
+    [20501, 25912, -16518, 780],
+    [20242, 25936, -16518, 1580],
+    [19983, 25973, -16518, 2380],
+    [19724, 26022, -16518, 3180],
+    [19465, 26084, -16518, 3980],
+    [19206, 26158, -16518, 4780],
+    [18947, 26245, -16518, 5580],
+    [18688, 26344, -16518, 6380],
+    [18429, 26456, -16518, 7180],
+    [18170, 26580, -16518, 7980],
+    [17911, 26717, -16518, 8780],
+    [17652, 26866, -16518, 9580],
+    [17393, 27028, -16518, 10380],
+    [17134, 27202, -16518, 11180],
+];
+
+pub const DENSE_PEG_CLUSTER: &[Sample] = &[
+    [22784, 17920, 0, 0],
+    [24576, 17664, 6, -2624],
+    [26112, 17664, 49, 0],
+    [22784, 19456, 0, 0],
+    [24576, 19200, 0, 0],
+    [26368, 19456, 2742, 1533],
+    [22784, 20992, 0, 0],
+    [24832, 20992, 0, 0],
+    [22665, 17939, 0, 0],
+    [24576, 17382, 5, -2563],
+    [26094, 17552, 1833, -1271],
+    [22665, 19472, 0, 0],
+    [24559, 19081, 0, 0],
+    [26514, 19495, 2677, 1497],
+    [22784, 21248, -901, 1776],
+    [24849, 21111, 0, 0],
+    [22642, 17946, 0, 0],
+    [24577, 17180, 4, -2503],
+    [26120, 17524, 1790, -1242],
+    [22602, 19485, -1196, 714],
+    [24551, 19079, 0, 0],
+    [26563, 19520, 2614, 1461],
+    [22776, 21404, -880, 1734],
+    [24849, 21111, 574, 1254],
+    [22651, 17688, -981, -1574],
+    [24577, 17084, 3, -2445],
+    [26367, 17555, 1748, -1213],
+    [22559, 19500, -1168, 697],
+    [24575, 19075, 41, 476],
+    [26603, 19542, 2552, 1426],
+    [22768, 21487, -860, 1693],
+    [24857, 21130, 560, 1224],
+    [22627, 17665, -959, -1538],
+    [24577, 17037, -223, -3761],
+    [26393, 17528, 1707, -1185],
+    [22537, 19486, -1141, 680],
+    [24575, 19090, 40, 464],
+    [26643, 19572, 3562, 2036],
+    [22757, 21537, -840, 1653],
+    [24865, 21149, 546, 1195],
+    [22616, 17544, -937, -1502],
+    [24511, 16960, -218, -3673],
+    [26481, 17527, 2668, -1831],
+    [22515, 19592, -1115, 664],
+    [24575, 19097, 39, 453],
+    [26698, 19603, 3478, 1988],
+    [22743, 21562, -1324, 2340],
+    [24873, 21167, 533, 1166],
+    [22578, 17527, -916, -1467],
+    [24530, 16895, -213, -3587],
+    [26522, 17498, 2605, -1789],
+    [22494, 19578, -2042, 687],
+    [24575, 19104, 38, 442],
+    [26752, 19634, 3396, 1941],
+    [22725, 21622, -1293, 2285],
+    [24881, 21185, 1027, 2040],
+    [22563, 17504, -1757, -2084],
+    [24526, 16838, -209, -3503],
+    [26562, 17470, 2543, -1748],
+    [22462, 19588, -1995, 670],
+    [24575, 19110, 9, 101],
+    [26805, 19664, 3316, 1895],
+    [22704, 21657, -1263, 2231],
+    [24897, 21216, 1002, 1992],
+    [22535, 17471, -1716, -2036],
+    [24522, 16783, -175, -4441],
+    [26601, 17442, 2483, -1708],
+    [22430, 19598, -1949, 654],
+    [24575, 19111, 8, 98],
+    [26856, 19693, 4067, 2303],
+    [22684, 21691, -1234, 2178],
+    [24912, 21247, 978, 1945],
+    [22508, 17439, -1676, -1989],
+    [24519, 16713, -171, -4337],
+    [26639, 17415, 2870, -1981],
+    [22399, 19608, -1904, 638],
+    [24575, 19112, 7, 95],
+    [26919, 19728, 3971, 2249],
+    [22664, 21725, -1597, 2794],
+    [24927, 21277, 955, 1899],
+    [22481, 17407, -1637, -1943],
+    [24516, 16645, -167, -4236],
+    [26683, 17384, 2802, -1935],
+    [22369, 19617, -2657, 660],
+    [24575, 19113, 6, 92],
+    [26981, 19763, 3877, 2196],
+    [22639, 21768, -1560, 2728],
+    [24941, 21306, 1330, 2594],
+    [22455, 17376, -2093, -2227],
+    [24513, 16578, -164, -4137],
+    [26726, 17353, 2736, -1890],
+    [22327, 19627, -2595, 644],
+    [24575, 19114, 25, -264],
+    [27041, 19797, 3786, 2144],
+    [22614, 21810, -1524, 2664],
+    [24961, 21346, 1298, 2533],
+    [22422, 17341, -2044, -2175],
+    [24510, 16513, -39, -4779],
+    [26768, 17323, 2671, -1846],
+    [22286, 19637, -2535, 628],
+    [24575, 19109, 24, -258],
+    [27100, 19830, 4295, 2339],
+    [22590, 21851, -1489, 2601],
+    [24981, 21385, 1267, 2473],
+    [22390, 17307, -1997, -2125],
+    [24509, 16438, -39, -4667],
+    [26809, 17294, 2933, -2009],
+    [22246, 19646, -2476, 613],
+    [24575, 19104, 23, -252],
+    [27167, 19866, 4194, 2284],
+    [22566, 21891, -1765, 3114],
+    [25000, 21423, 1237, 2415],
+    [22358, 17273, -1951, -2076],
+    [24508, 16365, -39, -4558],
+    [26854, 17262, 2864, -1962],
+    [22207, 19655, -3023, 548],
+    [24575, 19100, 22, -247],
+    [27232, 19901, 4095, 2230],
+    [22538, 21939, -1724, 3041],
+    [25019, 21460, 1499, 2938],
+    [22327, 17240, -2288, -2279],
+    [24507, 16293, -39, -4452],
+    [26898, 17231, 2796, -1917],
+    [22159, 19663, -2953, 535],
+    [24575, 19096, 295, -702],
+    [27295, 19935, 3999, 2177],
+    [22511, 21986, -1684, 2969],
+    [25042, 21505, 1463, 2869],
+    [22291, 17204, -2235, -2226],
+    [24506, 16223, 35, -4870],
+    [26941, 17201, 2730, -1873],
+    [22112, 19671, -2884, 522],
+    [24579, 19085, 288, -686],
+    [27357, 19969, 4378, 2300],
+    [22484, 22032, -1645, 2899],
+    [25064, 21549, 1428, 2801],
+    [22256, 17169, -2183, -2174],
+    [24506, 16146, 34, -4756],
+    [26983, 17171, 2666, -1830],
+    [22066, 19679, -2817, 509],
+    [24583, 19074, 281, -670],
+    [27425, 20004, 4275, 2246],
+    [22458, 22077, -1547, 3238],
+    [25086, 21592, 1394, 2735],
+    [22221, 17135, -2132, -2124],
+    [24506, 16071, 33, -4645],
+    [27024, 17142, 2603, -1788],
+    [22021, 19686, -3235, 449],
+    [24587, 19063, 274, -655],
+    [27491, 20039, 4174, 2193],
+    [22433, 22127, -1511, 3162],
+    [25107, 21634, 1582, 3120],
+    [22187, 17101, -2370, -2269],
+    [24506, 15998, 32, -4537],
+    [27064, 17114, 2541, -1747],
+    [21970, 19693, -3160, 438],
+    [24591, 19052, 267, -640],
+    [27556, 20073, 4076, 2141],
+    [22409, 22176, -1476, 3087],
+    [25131, 21682, 1544, 3046],
+    [22149, 17065, -2315, -2216],
+    [24506, 15927, 93, -4855],
+    [27103, 17086, 2481, -1707],
+    [21920, 19699, -3086, 427],
+    [24595, 19042, 260, -625],
+    [27619, 20106, 4357, 2217],
+    [22385, 22224, -1442, 3014],
+    [25155, 21729, 1507, 2974],
+    [22112, 17030, -2261, -2165],
+    [24507, 15851, 90, -4742],
+    [27141, 17059, 2422, -1667],
+    [21871, 19705, -3014, 416],
+    [24599, 19032, 253, -611],
+    [27687, 20140, 4254, 2165],
+    [22362, 22271, -1409, 2943],
+    [25178, 21775, 1471, 2904],
+    [22076, 16996, -2209, -2115],
+    [24508, 15776, 87, -4631],
+    [27178, 17032, 2365, -1628],
+    [21823, 19711, -3248, 436],
+    [24602, 19022, 247, -597],
+    [27753, 20173, 4154, 2114],
+    [22339, 22316, -1376, 2874],
+    [25200, 21820, 1537, 3135],
+    [22041, 16962, -2158, -2066],
+    [24509, 15703, 84, -4523],
+    [27214, 17006, 2309, -1590],
+    [21772, 19717, -3172, 425],
+    [24605, 19012, 241, -584],
+    [27817, 20206, 4056, 2064],
+    [22317, 22360, -1344, 2806],
+    [25224, 21868, 1500, 3061],
+    [22007, 16929, -2108, -2018],
+    [24510, 15632, 131, -4766],
+    [27250, 16981, 2254, -1553],
+    [21722, 19723, -3098, 415],
+    [24608, 19002, 235, -571],
+    [27880, 20238, 4262, 2111],
+    [22296, 22403, -1313, 2740],
+    [25247, 21915, 1464, 2989],
+    [21974, 16897, -2059, -1971],
+    [24512, 15557, 127, -4655],
+    [27285, 16956, 2201, -1517],
+    [21673, 19729, -3026, 405],
+    [24611, 18993, 229, -558],
+    [27946, 20270, 4162, 2061],
+    [22275, 22445, -1283, 2675],
+    [25269, 21961, 1429, 2918],
+    [21941, 16866, -2011, -1925],
+    [24513, 15484, 124, -4546],
+    [27319, 16932, 2149, -1482],
+    [21625, 19735, -3222, 415],
+    [24614, 18984, 223, -545],
+    [28011, 20302, 4064, 2012],
+    [22254, 22486, -1253, 2612],
+    [25291, 22006, 1482, 3090],
+    [21909, 16835, -1964, -1880],
+    [24514, 15412, 121, -4440],
+    [27352, 16908, 2098, -1448],
+    [21574, 19741, -3147, 405],
+    [24617, 18975, 217, -533],
+    [28074, 20333, 3968, 1964],
+    [22234, 22526, -1224, 2550],
+    [25314, 22054, 1447, 3017],
+    [21878, 16805, -1918, -1836],
+    [24515, 15342, 150, -4633],
+    [27384, 16885, 2048, -1415],
+    [21524, 19747, -3074, 395],
+    [24620, 18966, 211, -521],
+    [28136, 20363, 4132, 1998],
+    [22214, 22565, -1196, 2490],
+    [25336, 22101, 1413, 2946],
+    [21848, 16776, -1874, -1793],
+    [24517, 15269, 146, -4525],
+    [27416, 16862, 2000, -1382],
+    [21475, 19753, -3002, 385],
+    [24623, 18957, 206, -509],
+    [28200, 20394, 4035, 1951],
+    [22195, 22603, -1168, 2431],
+    [25358, 22147, 1379, 2876],
+    [21818, 16747, -1831, -1751],
+    [24519, 15198, 142, -4419],
+    [27447, 16840, 1953, -1350],
+    [21428, 19759, -3159, 395],
+    [24626, 18949, 201, -498],
+    [28263, 20424, 3940, 1905],
+    [22176, 22640, -1141, 2374],
+    [25379, 22191, 1422, 3001],
+    [21789, 16719, -1789, -1710],
+    [24521, 15128, 138, -4316],
+    [27477, 16818, 1907, -1319],
+    [21378, 19765, -3085, 385],
+    [24629, 18941, 196, -487],
+    [28324, 20453, 3847, 1860],
+    [22158, 22677, -1115, 2318],
+    [25401, 22237, 1388, 2930],
+    [21761, 16692, -1748, -1670],
+    [24523, 15060, 161, -4470],
+    [27506, 16797, 1862, -1289],
+    [21329, 19771, -3013, 375],
+    [24632, 18933, 191, -476],
+    [28384, 20482, 3891, 1854],
+    [22140, 22713, -1089, 2263],
+    [25422, 22282, 1355, 2861],
+    [21733, 16665, -1708, -1631],
+    [24525, 14990, 157, -4366],
+    [27535, 16776, 1818, -1259],
+    [21281, 19776, -2943, 366],
+    [24634, 18925, 186, -465],
+    [28444, 20510, 3799, 1810],
+    [22122, 22748, -1064, 2209],
+    [25443, 22326, 1323, 2793],
+    [21706, 16639, -1668, -1593],
+    [24527, 14921, 153, -4264],
+    [27563, 16756, 1775, -1230],
+    [21235, 19781, -3068, 375],
+    [24636, 18917, 181, -455],
+    [28503, 20538, 3709, 1767],
+    [22105, 22782, -1040, 2157],
+    [25463, 22369, 1359, 2899],
+    [21679, 16614, -1629, -1556],
+    [24529, 14854, 149, -4165],
+    [27590, 16736, 1733, -1202],
+    [21187, 19786, -2997, 366],
+    [24638, 18909, 176, -445],
+    [28560, 20565, 3622, 1725],
+    [22088, 22815, -1016, 2106],
+    [25484, 22414, 1327, 2831],
+    [21653, 16589, -1591, -1520],
+    [24531, 14788, 166, -4206],
+    [27617, 16717, 1692, -1174],
+    [21140, 19791, -2927, 357],
+    [24640, 18902, 171, -435],
+    [28616, 20591, 3636, 1624],
+    [22072, 22847, -993, 2056],
+    [25504, 22458, 1295, 2764],
+    [21628, 16565, -1554, -1485],
+    [24533, 14722, 162, -4108],
+    [27643, 16698, 1652, -1147],
+    [21094, 19796, -2859, 348],
+    [24642, 18895, 166, -425],
+    [28672, 20616, 3550, 1585],
+    [22056, 22879, -970, 2007],
+    [25524, 22501, 1264, 2699],
+    [21603, 16541, -1518, -1451],
+    [24535, 14657, 158, -4012],
+    [27668, 16680, 1613, -1121],
+    [21049, 19801, -2977, 358],
+    [24644, 18888, 162, -416],
+    [28727, 20640, 3466, 1547],
+    [22040, 22910, -948, 1959],
+    [25543, 22543, 1290, 2776],
+    [21579, 16518, -1483, -1417],
+    [24537, 14594, 154, -3918],
+    [27693, 16662, 1575, -1095],
+    [21002, 19806, -2908, 349],
+    [24646, 18881, 158, -407],
+    [28781, 20664, 3384, 1510],
+    [22025, 22940, -926, 1913],
+    [25563, 22586, 1259, 2710],
+];
@@ -1,82 +1,103 @@
 use core::fmt::Debug;
 
 use agb::fixnum::{Vector2D, num, vec2};
+use alloc::vec::Vec;
 use heapless::Vec as HeaplessVec;
 
-use crate::Coordinates;
 use crate::error::Error;
+use crate::{Coordinates, Fixed};
 
-const MAX_NB_NEIGHBOR_PER_CELL: usize = 8;
-const NB_WIDTH: usize = 24;
-const NB_HEIGHT: usize = 20;
-const SHIFT_VALUE: usize = 3;
+/// The default grid: 24 by 20 cells of 8 pixels, enough for the GBA screen.
+pub const WIDTH: usize = 24;
+pub const HEIGHT: usize = 20;
+pub const CELL_SHIFT: usize = 3;
 
-pub struct Grid2D<Index = u8> {
-    grid: [[HeaplessVec<Index, MAX_NB_NEIGHBOR_PER_CELL>; NB_HEIGHT]; NB_WIDTH],
-    neighbors: HeaplessVec<Index, 64>,
+const MAX_NB_NEIGHBOR_PER_CELL: usize = 8;
+const MAX_NEIGHBORS: usize = 64;
+
+type Cell<Index> = HeaplessVec<Index, MAX_NB_NEIGHBOR_PER_CELL>;
+
+/// Buckets objects by position so that only nearby ones are looked at.
+/// `WIDTH` by `HEIGHT` cells of `1 << CELL_SHIFT` pixels cover the world,
+/// anything outside is clamped onto the border cells.
+///
+/// Crowding never fails: full cells spill into a shared overflow list, and
+/// queries past `MAX_NEIGHBORS` are cut short and counted.
+pub struct Grid2D<
+    const WIDTH: usize,
+    const HEIGHT: usize,
+    const CELL_SHIFT: usize,
+    Index = u8,
+> {
+    grid: [[Cell<Index>; HEIGHT]; WIDTH],
+    /// Objects that didn't fit in their cell, with that cell.
+    overflow: Vec<(usize, usize, Index)>,
+    neighbors: HeaplessVec<Index, MAX_NEIGHBORS>,
+    truncated: u32,
 }
 
-impl<Index> Grid2D<Index>
+impl<const WIDTH: usize, const HEIGHT: usize, const CELL_SHIFT: usize, Index>
+    Grid2D<WIDTH, HEIGHT, CELL_SHIFT, Index>
 where
     Index: TryFrom<usize> + Default + Copy + PartialEq + Debug,
 {
+    /// Keeps a position within the world covered by the grid.
+    pub fn clamp(position: Coordinates) -> Coordinates {
+        let max_x = Fixed::new(((WIDTH - 1) << CELL_SHIFT) as i32);
+        let max_y = Fixed::new(((HEIGHT - 1) << CELL_SHIFT) as i32);
+
+        vec2(
+            position.x.clamp(num!(0), max_x),
+            position.y.clamp(num!(0), max_y),
+        )
+    }
+
     fn spatial_to_grid_coords(
         coordinates: &Coordinates,
     ) -> Result<(usize, usize), Error> {
-        const GRID_MAX_COORD: i32 =
-            ((NB_WIDTH - 1) * (1 << SHIFT_VALUE)) as i32;
-        const GRID_MIN_COORD: i32 = 0;
-
-        let clamped_x = coordinates
-            .x
-            .clamp(num!(GRID_MIN_COORD), num!(GRID_MAX_COORD));
-        let clamped_y = coordinates
-            .y
-            .clamp(num!(GRID_MIN_COORD), num!(GRID_MAX_COORD));
-
-        let round = vec2(clamped_x, clamped_y).round();
+        let round = Self::clamp(*coordinates).round();
         let unsigned =
             Vector2D::new(round.x.unsigned_abs(), round.y.unsigned_abs());
 
         match (
-            (unsigned.x >> SHIFT_VALUE).try_into(),
-            (unsigned.y >> SHIFT_VALUE).try_into(),
+            (unsigned.x >> CELL_SHIFT).try_into(),
+            (unsigned.y >> CELL_SHIFT).try_into(),
         ) {
             (Err(_), _) | (_, Err(_)) => Err(Error::Convertion),
             (Ok(x), Ok(y)) => Ok((x, y)),
         }
     }
 
-    fn insert_in_cell(
-        index: Index,
-        cell: &mut HeaplessVec<Index, MAX_NB_NEIGHBOR_PER_CELL>,
-    ) -> Result<(), Error> {
-        cell.push(index).map_err(|_| Error::CellFull)
+    fn insert(&mut self, index: Index, (x, y): (usize, usize)) {
+        if self.grid[x][y].push(index).is_err() {
+            self.overflow.push((x, y, index));
+        }
     }
 
-    fn try_remove_in_cell(
-        index: Index,
-        cell: &mut HeaplessVec<Index, MAX_NB_NEIGHBOR_PER_CELL>,
-    ) -> Result<bool, Error> {
-        if let Some(pos) = cell.iter().position(|&x| x == index) {
+    fn remove(&mut self, index: Index, (x, y): (usize, usize)) {
+        let cell = &mut self.grid[x][y];
+        if let Some(pos) = cell.iter().position(|&i| i == index) {
             cell.swap_remove(pos);
-            Ok(true)
-        } else {
-            Ok(false)
+        } else if let Some(pos) = self
+            .overflow
+            .iter()
+            .position(|&entry| entry == (x, y, index))
+        {
+            self.overflow.swap_remove(pos);
         }
     }
 
-
     pub fn new(
         positions: &[Coordinates],
         collidable: &[bool],
     ) -> Result<Self, Error> {
-        let grid: [[HeaplessVec<Index, MAX_NB_NEIGHBOR_PER_CELL>; NB_HEIGHT];
-            NB_WIDTH] = Default::default();
-
         let mut res = Self {
-            grid,
+            grid: core::array::from_fn(|_| {
+                core::array::from_fn(|_| HeaplessVec::new())
+            }),
+            overflow: Vec::new(),
             neighbors: HeaplessVec::new(),
+            truncated: 0,
         };
 
         for (i, p) in positions.iter().enumerate() {
@@ -84,15 +105,14 @@ where
                 continue;
             }
 
-            let (x, y) = Self::spatial_to_grid_coords(p)?;
+            let cell = Self::spatial_to_grid_coords(p)?;
             let index = Index::try_from(i).map_err(|_| Error::IndexTooBig)?;
-            Self::insert_in_cell(index, &mut res.grid[x][y])?;
+            res.insert(index, cell);
         }
 
         Ok(res)
     }
 
-
     pub fn update(
         &mut self,
         index: usize,
@@ -105,15 +125,12 @@ where
 
         let index = Index::try_from(index).map_err(|_| Error::IndexTooBig)?;
 
-        let (ox, oy) = Self::spatial_to_grid_coords(&old_position)?;
-        let (nx, ny) = Self::spatial_to_grid_coords(&new_position)?;
-        
-        let was_removed = Self::try_remove_in_cell(index, &mut self.grid[ox][oy])?;
-        if !was_removed {
-            // Object wasn't in expected cell, treating as new object (this is normal for newly spawned objects)
-        }
-        
-        Self::insert_in_cell(index, &mut self.grid[nx][ny])?;
+        let old_cell = Self::spatial_to_grid_coords(&old_position)?;
+        let new_cell = Self::spatial_to_grid_coords(&new_position)?;
+
+        // Newly spawned objects aren't in their old cell, which is fine.
+        self.remove(index, old_cell);
+        self.insert(index, new_cell);
 
         Ok(())
     }
@@ -129,30 +146,28 @@ where
 
         for x in cx.saturating_sub(cell_radius)..=cx + cell_radius {
             for y in cy.saturating_sub(cell_radius)..=cy + cell_radius {
-                if x >= NB_WIDTH || y >= NB_HEIGHT {
+                if x >= WIDTH || y >= HEIGHT {
                     continue;
                 }
 
-                for &i in self.grid[x][y].iter() {
-                    self.neighbors
-                        .push(i)
-                        .map_err(|_| Error::TooManyNeighbors)?;
+                let spilled = self
+                    .overflow
+                    .iter()
+                    .filter(|&&(ox, oy, _)| (ox, oy) == (x, y))
+                    .map(|&(_, _, i)| i);
+                for i in self.grid[x][y].iter().copied().chain(spilled) {
+                    if self.neighbors.push(i).is_err() {
+                        self.truncated += 1;
+                    }
                 }
             }
         }
 
         Ok(&self.neighbors)
     }
-}
 
-pub fn clamp_position_to_grid(position: Coordinates) -> Coordinates {
-    const GRID_MAX_COORD: i32 = ((NB_WIDTH - 1) * (1 << SHIFT_VALUE)) as i32;
-    const GRID_MIN_COORD: i32 = 0;
-
-    let clamped_x =
-        position.x.clamp(num!(GRID_MIN_COORD), num!(GRID_MAX_COORD));
-    let clamped_y =
-        position.y.clamp(num!(GRID_MIN_COORD), num!(GRID_MAX_COORD));
-
-    vec2(clamped_x, clamped_y)
+    /// How many neighbours were left out of queries since the last call.
+    pub fn take_truncated(&mut self) -> u32 {
+        core::mem::take(&mut self.truncated)
+    }
 }
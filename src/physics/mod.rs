@@ -1,9 +1,17 @@
 use crate::error::Error;
 use crate::{Coordinates, Fixed, Force};
 use agb::fixnum::{num, vec2};
+use agb::rng::RandomNumberGenerator;
 use alloc::vec::Vec;
 
+#[cfg(test)]
+pub mod golden;
 mod grid;
+mod motion;
+#[cfg(test)]
+pub mod scenario;
+
+pub use motion::Motion;
 
 #[derive(Clone, Copy)]
 pub struct PhysicsConfig {
@@ -16,6 +24,8 @@ pub struct PhysicsConfig {
     pub gravity: i32,
     pub repulsion_strength: i32,
     pub object_radius: i32,
+    /// Largest random kick, per axis, given to a peg bouncing off a wall.
+    pub jitter: i32,
 }
 
 const WALL_BOUNCE_DAMPING: f32 = 0.9;
@@ -26,26 +36,36 @@ const MIN_DISTANCE_THRESHOLD: f32 = 0.001;
 const VELOCITY_DAMPING: f32 = 0.98;
 const MIN_BOUNCE_SPEED_SQUARED: i32 = 625;
 const HALF_MIN_VELOCITY: i32 = 12;
-const RANDOMNESS_INTERVAL: usize = 4;
-const RAND_X_MULTIPLIER: usize = 31;
-const RAND_Y_MULTIPLIER: usize = 37;
-const RAND_X_OFFSET: usize = 17;
-const RAND_Y_OFFSET: usize = 23;
-const RAND_MODULO: usize = 10;
-const RAND_CENTER_OFFSET: i32 = 5;
+const RANDOMNESS_INTERVAL: i32 = 4;
 const MIN_DISTANCE_CHECK: f32 = 0.1;
 const OVERLAP_ADJUSTMENT: f32 = 0.5;
 const VELOCITY_CLAMP_LIMIT: i32 = 50;
 const NEIGHBOR_SEARCH_RADIUS: usize = 1;
 const FORCE_SEARCH_RADIUS: usize = 5;
 
-pub struct Physics<const N: usize> {
-    neighbors: grid::Grid2D,
+/// Moves the ball and the `N` pegs. The world is covered by a spatial grid
+/// of `WIDTH` by `HEIGHT` cells of `1 << CELL_SHIFT` pixels, pegs never
+/// leave it.
+pub struct Physics<
+    const N: usize,
+    const WIDTH: usize = { grid::WIDTH },
+    const HEIGHT: usize = { grid::HEIGHT },
+    const CELL_SHIFT: usize = { grid::CELL_SHIFT },
+> {
+    neighbors: grid::Grid2D<WIDTH, HEIGHT, CELL_SHIFT>,
     touched: Vec<usize>,
     frame_counter: usize,
+    motions: [Motion; N],
+    anchors: [Coordinates; N],
 }
 
-impl<const N: usize> Physics<N> {
+impl<
+    const N: usize,
+    const WIDTH: usize,
+    const HEIGHT: usize,
+    const CELL_SHIFT: usize,
+> Physics<N, WIDTH, HEIGHT, CELL_SHIFT>
+{
     pub fn new(
         positions: &[Coordinates; N],
         collidable: &[bool; N],
@@ -54,9 +74,26 @@ impl<const N: usize> Physics<N> {
             neighbors: grid::Grid2D::new(positions, collidable)?,
             touched: Vec::new(),
             frame_counter: 0,
+            motions: [Motion::Drifting; N],
+            anchors: *positions,
         })
     }
 
+    fn clamp_to_grid(position: Coordinates) -> Coordinates {
+        grid::Grid2D::<WIDTH, HEIGHT, CELL_SHIFT>::clamp(position)
+    }
+
+    /// Gives a peg a new way of moving, anchored at `anchor`.
+    pub fn set_motion(
+        &mut self,
+        index: usize,
+        motion: Motion,
+        anchor: Coordinates,
+    ) {
+        self.motions[index] = motion;
+        self.anchors[index] = anchor;
+    }
+
     fn hit_horizontal_walls(
         position: &mut Coordinates,
         velocity: &mut Force,
@@ -191,7 +228,7 @@ impl<const N: usize> Physics<N> {
                 position = closest_point + normal * radius;
 
                 let velocity_along_normal = velocity.dot(normal);
-                velocity -= -normal
+                velocity -= normal
                     * (velocity_along_normal * num!(2))
                     * num!(WALL_BOUNCE_DAMPING);
             }
@@ -279,6 +316,7 @@ impl<const N: usize> Physics<N> {
         Ok((position, velocity, &self.touched))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn move_and_collide(
         &mut self,
         mut position: Coordinates,
@@ -311,6 +349,7 @@ impl<const N: usize> Physics<N> {
         Ok((position, velocity, touched))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn apply_repulsion_forces(
         &mut self,
         index: usize,
@@ -366,7 +405,7 @@ impl<const N: usize> Physics<N> {
         index: usize,
         positions: &mut [Coordinates; N],
         velocities: &mut [Force; N],
-        frame_counter: usize,
+        rng: &mut RandomNumberGenerator,
         config: &PhysicsConfig,
     ) {
         velocities[index] *= num!(VELOCITY_DAMPING);
@@ -382,8 +421,8 @@ impl<const N: usize> Physics<N> {
         if velocities[index] != old_velocity {
             Self::ensure_minimum_velocity(
                 &mut velocities[index],
-                index,
-                frame_counter,
+                rng,
+                config.jitter,
             );
         }
     }
@@ -405,30 +444,25 @@ impl<const N: usize> Physics<N> {
         }
     }
 
+    /// Now and then, nudges the velocity by up to `jitter` on each axis.
     fn add_randomness(
         velocity: &mut Force,
-        index: usize,
-        frame_counter: usize,
+        rng: &mut RandomNumberGenerator,
+        jitter: i32,
     ) {
-        if (frame_counter + index) % RANDOMNESS_INTERVAL == 0 {
-            let rand_x = ((index * RAND_X_MULTIPLIER
-                + frame_counter * RAND_X_OFFSET)
-                % RAND_MODULO) as i32
-                - RAND_CENTER_OFFSET;
-            let rand_y = ((index * RAND_Y_MULTIPLIER
-                + frame_counter * RAND_Y_OFFSET)
-                % RAND_MODULO) as i32
-                - RAND_CENTER_OFFSET;
-
-            velocity.x += Fixed::new(rand_x);
-            velocity.y += Fixed::new(rand_y);
+        if jitter <= 0 || rng.next_i32().rem_euclid(RANDOMNESS_INTERVAL) != 0 {
+            return;
         }
+
+        let range = jitter * 2 + 1;
+        velocity.x += Fixed::new(rng.next_i32().rem_euclid(range) - jitter);
+        velocity.y += Fixed::new(rng.next_i32().rem_euclid(range) - jitter);
     }
 
     fn ensure_minimum_velocity(
         velocity: &mut Force,
-        index: usize,
-        frame_counter: usize,
+        rng: &mut RandomNumberGenerator,
+        jitter: i32,
     ) {
         let speed_squared = velocity.magnitude_squared();
 
@@ -438,9 +472,12 @@ impl<const N: usize> Physics<N> {
             Self::boost_low_velocity_components(velocity);
         }
 
-        Self::add_randomness(velocity, index, frame_counter);
+        Self::add_randomness(velocity, rng, jitter);
     }
 
+    /// Moves every collidable peg one frame. `rng` drives the jitter, so the
+    /// same seed replays the same motion.
+    #[allow(clippy::too_many_arguments)]
     pub fn move_from_fields<const PARTIAL: usize>(
         &mut self,
         positions: &mut [Coordinates; N],
@@ -449,6 +486,7 @@ impl<const N: usize> Physics<N> {
         force_radius_squared: &[Fixed; N],
         delta: Fixed,
         config: &PhysicsConfig,
+        rng: &mut RandomNumberGenerator,
     ) -> Result<(), Error> {
         self.frame_counter = self.frame_counter.wrapping_add(1);
 
@@ -457,6 +495,12 @@ impl<const N: usize> Physics<N> {
                 continue;
             }
 
+            let motion = self.motions[i];
+            if motion.is_kinematic() {
+                self.follow_path(i, positions, velocities, config)?;
+                continue;
+            }
+
             let position = positions[i];
             let force = force_radius_squared[i];
             let velocity = velocities[i];
@@ -473,10 +517,13 @@ impl<const N: usize> Physics<N> {
                 )?;
             }
 
-            Self::update_object_movement(i, positions, velocities, self.frame_counter, config);
+            if let Motion::Spring { .. } = motion {
+                velocities[i] += motion.pull(self.anchors[i], position) * delta;
+            }
+
+            Self::update_object_movement(i, positions, velocities, rng, config);
 
-            let new_pos =
-                grid::clamp_position_to_grid(position + velocity * delta);
+            let new_pos = Self::clamp_to_grid(position + velocity * delta);
 
             positions[i] = new_pos;
 
@@ -493,7 +540,7 @@ impl<const N: usize> Physics<N> {
             }
 
             let old_position = positions[i];
-            let new_pos = grid::clamp_position_to_grid(positions[i]);
+            let new_pos = Self::clamp_to_grid(positions[i]);
 
             if new_pos != old_position {
                 positions[i] = new_pos;
@@ -504,6 +551,38 @@ impl<const N: usize> Physics<N> {
         Ok(())
     }
 
+    /// Moves a kinematic peg to where its path has it this frame.
+    fn follow_path(
+        &mut self,
+        index: usize,
+        positions: &mut [Coordinates; N],
+        velocities: &mut [Force; N],
+        config: &PhysicsConfig,
+    ) -> Result<(), Error> {
+        let radius = config.object_radius;
+        let target = self.motions[index]
+            .position_at(self.anchors[index], self.frame_counter);
+        let new_position = Self::clamp_to_grid(vec2(
+            target.x.clamp(
+                Fixed::new(config.left_wall + radius),
+                Fixed::new(config.right_wall - radius),
+            ),
+            target.y.clamp(
+                Fixed::new(config.up_wall + radius),
+                Fixed::new(config.down_wall - radius),
+            ),
+        ));
+        let old_position = positions[index];
+
+        velocities[index] = vec2(num!(0), num!(0));
+        if new_position != old_position {
+            positions[index] = new_position;
+            self.neighbors.update(index, old_position, new_position)?;
+        }
+
+        Ok(())
+    }
+
     fn resolve_object_collisions(
         &mut self,
         positions: &mut [Coordinates; N],
@@ -528,7 +607,11 @@ impl<const N: usize> Physics<N> {
 
             for &j in neighbors {
                 let j = j as usize;
-                if j <= i || !collidable[j] {
+                let kinematic = (
+                    self.motions[i].is_kinematic(),
+                    self.motions[j].is_kinematic(),
+                );
+                if j <= i || !collidable[j] || kinematic == (true, true) {
                     continue;
                 }
 
@@ -544,8 +627,16 @@ impl<const N: usize> Physics<N> {
 
                     let separation =
                         normal * overlap * num!(OVERLAP_ADJUSTMENT);
-                    position_updates.push((i, positions[i] + separation));
-                    position_updates.push((j, positions[j] - separation));
+                    // A peg on a path doesn't budge, the other one takes the
+                    // whole push.
+                    let still = vec2(num!(0), num!(0));
+                    let (push_i, push_j) = match kinematic {
+                        (true, _) => (still, separation * 2),
+                        (_, true) => (separation * 2, still),
+                        _ => (separation, separation),
+                    };
+                    position_updates.push((i, positions[i] + push_i));
+                    position_updates.push((j, positions[j] - push_j));
 
                     let relative_velocity = velocities[i] - velocities[j];
                     let velocity_along_normal = relative_velocity.dot(normal);
@@ -554,8 +645,12 @@ impl<const N: usize> Physics<N> {
                         let impulse = normal
                             * velocity_along_normal
                             * num!(BOUNCE_DAMPING);
-                        velocities[i] -= impulse;
-                        velocities[j] += impulse;
+                        if !kinematic.0 {
+                            velocities[i] -= impulse;
+                        }
+                        if !kinematic.1 {
+                            velocities[j] += impulse;
+                        }
                     }
                 }
             }
@@ -572,6 +667,11 @@ impl<const N: usize> Physics<N> {
         Ok(())
     }
 
+    /// Neighbours skipped by crowded grid queries since the last call.
+    pub fn take_truncated_queries(&mut self) -> u32 {
+        self.neighbors.take_truncated()
+    }
+
     pub fn force_move(
         &mut self,
         index: usize,
@@ -581,6 +681,7 @@ impl<const N: usize> Physics<N> {
         let old = positions[index];
         positions[index] = at;
         self.neighbors.update(index, old, at)?;
+        self.set_motion(index, Motion::Drifting, at);
 
         Ok(())
     }
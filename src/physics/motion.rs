@@ -0,0 +1,64 @@
+use crate::{Coordinates, Fixed, Force};
+use agb::fixnum::{Vector2D, num, vec2};
+
+/// How a peg moves between shots. Only `Drifting` and `Spring` pegs react to
+/// the repulsion field and to being bumped, the others follow their path and
+/// push everything else out of the way.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Motion {
+    /// Never moves.
+    Static,
+    /// Pushed around by its neighbours and kept lively by jitter.
+    Drifting,
+    /// Goes around its anchor once every `period` frames.
+    Orbiting { radius: i32, period: u16 },
+    /// Swings from its anchor to `anchor + offset` and back every `period`
+    /// frames.
+    Oscillating { offset: Vector2D<i32>, period: u16 },
+    /// Drifts, but is pulled back towards its anchor. `stiffness` is the pull
+    /// per pixel away, in pixels per second squared.
+    Spring { stiffness: i32 },
+}
+
+impl Motion {
+    /// Whether the peg follows a fixed path instead of being pushed around.
+    pub fn is_kinematic(self) -> bool {
+        matches!(
+            self,
+            Motion::Static
+                | Motion::Orbiting { .. }
+                | Motion::Oscillating { .. }
+        )
+    }
+
+    /// Where a kinematic peg is `frame` frames into its path.
+    pub fn position_at(self, anchor: Coordinates, frame: usize) -> Coordinates {
+        match self {
+            Motion::Orbiting { radius, period } => {
+                let turn = Self::turn(frame, period);
+                anchor + vec2(turn.cos(), turn.sin()) * radius
+            }
+            Motion::Oscillating { offset, period } => {
+                let turn = Self::turn(frame, period);
+                let progress = (num!(1) - turn.cos()) / 2;
+                anchor
+                    + vec2(Fixed::new(offset.x), Fixed::new(offset.y))
+                        * progress
+            }
+            Motion::Static | Motion::Drifting | Motion::Spring { .. } => anchor,
+        }
+    }
+
+    /// The pull of a spring peg towards its anchor.
+    pub fn pull(self, anchor: Coordinates, position: Coordinates) -> Force {
+        match self {
+            Motion::Spring { stiffness } => (anchor - position) * stiffness,
+            _ => vec2(num!(0), num!(0)),
+        }
+    }
+
+    fn turn(frame: usize, period: u16) -> Fixed {
+        let period = period.max(1) as usize;
+        Fixed::new((frame % period) as i32) / period as i32
+    }
+}
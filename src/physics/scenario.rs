@@ -0,0 +1,174 @@
+//! Fixed setups replayed by the physics regression tests. Every frame, each
+//! tracked body is sampled as raw fixed point values so that any change to
+//! the physics shows up as a difference against the recorded trajectories in
+//! `golden`.
+
+use super::{Physics, PhysicsConfig};
+use crate::{Coordinates, Fixed, Force};
+use agb::fixnum::{num, vec2};
+use agb::rng::RandomNumberGenerator;
+use alloc::vec::Vec;
+
+/// Position then velocity, as `Fixed::to_raw` values.
+pub type Sample = [i32; 4];
+
+const DELTA_TIME: f32 = 1.0 / 60.0;
+
+const BALL_CONFIG: PhysicsConfig = PhysicsConfig {
+    left_wall: 20,
+    up_wall: 0,
+    right_wall: 180,
+    down_wall: 150,
+    moving_radius: 4,
+    static_radius: 3,
+    gravity: 200,
+    repulsion_strength: 0,
+    object_radius: 1,
+    jitter: 0,
+};
+
+const PEG_CONFIG: PhysicsConfig = PhysicsConfig {
+    left_wall: 20,
+    up_wall: 20,
+    right_wall: 180,
+    down_wall: 130,
+    moving_radius: 3,
+    static_radius: 3,
+    gravity: 0,
+    repulsion_strength: 3000,
+    object_radius: 4,
+    jitter: 5,
+};
+
+fn sample(position: Coordinates, velocity: Force) -> Sample {
+    [
+        position.x.to_raw(),
+        position.y.to_raw(),
+        velocity.x.to_raw(),
+        velocity.y.to_raw(),
+    ]
+}
+
+/// Moves a ball for `frames` frames and samples it after each one.
+fn fly<const N: usize>(
+    pegs: &[Coordinates; N],
+    walls: &[(Coordinates, Coordinates)],
+    mut position: Coordinates,
+    mut velocity: Force,
+    frames: usize,
+    config: &PhysicsConfig,
+) -> Vec<Sample> {
+    let collidable = [true; N];
+    let mut physics =
+        Physics::<N>::new(pegs, &collidable).expect("physics init failed");
+
+    (0..frames)
+        .map(|_| {
+            let (new_position, new_velocity, _) = physics
+                .move_and_collide(
+                    position,
+                    velocity,
+                    pegs,
+                    &collidable,
+                    num!(DELTA_TIME),
+                    walls,
+                    config,
+                )
+                .expect("ball step failed");
+            (position, velocity) = (new_position, new_velocity);
+            sample(position, velocity)
+        })
+        .collect()
+}
+
+/// A ball dropped slightly off the centre of a lone peg.
+pub fn ball_vs_single_peg() -> Vec<Sample> {
+    fly(
+        &[vec2(num!(80), num!(60))],
+        &[],
+        vec2(num!(82), num!(30)),
+        vec2(num!(0), num!(0)),
+        60,
+        &BALL_CONFIG,
+    )
+}
+
+/// A weightless ball thrown into the top left corner.
+pub fn corner_escape() -> Vec<Sample> {
+    let config = PhysicsConfig {
+        up_wall: 10,
+        gravity: 0,
+        ..BALL_CONFIG
+    };
+    fly(
+        &[vec2(num!(150), num!(120))],
+        &[],
+        vec2(num!(40), num!(30)),
+        vec2(num!(-60), num!(-60)),
+        45,
+        &config,
+    )
+}
+
+/// A ball thrown at the outer side of a bucket, then falling onto its rim.
+pub fn bucket_wall_hits() -> Vec<Sample> {
+    let (top, bottom) = (num!(110), num!(126));
+    let walls = [
+        (vec2(num!(100), top), vec2(num!(100), bottom)),
+        (vec2(num!(132), top), vec2(num!(132), bottom)),
+    ];
+    fly(
+        &[vec2(num!(40), num!(40))],
+        &walls,
+        vec2(num!(70), num!(90)),
+        vec2(num!(60), num!(-10)),
+        60,
+        &BALL_CONFIG,
+    )
+}
+
+pub const DENSE_PEG_COUNT: usize = 8;
+
+/// Pegs packed closer than their force radii, pushing each other apart.
+pub fn dense_peg_cluster() -> Vec<Sample> {
+    const N: usize = DENSE_PEG_COUNT;
+    const FORCE_RADII: [i32; 2] = [10, 15];
+
+    let mut positions: [Coordinates; N] = core::array::from_fn(|i| {
+        vec2(
+            Fixed::new(90 + (i % 3) as i32 * 6),
+            Fixed::new(70 + (i / 3) as i32 * 6),
+        )
+    });
+    let mut velocities = [vec2(num!(0), num!(0)); N];
+    let force_radius_squared: [Fixed; N] = core::array::from_fn(|i| {
+        let radius = Fixed::new(FORCE_RADII[i % FORCE_RADII.len()]);
+        radius * radius
+    });
+    let collidable = [true; N];
+    let mut physics = Physics::<N>::new(&positions, &collidable)
+        .expect("physics init failed");
+    let mut rng = RandomNumberGenerator::new();
+
+    let mut samples = Vec::new();
+    for _ in 0..40 {
+        physics
+            .move_from_fields::<4>(
+                &mut positions,
+                &mut velocities,
+                &collidable,
+                &force_radius_squared,
+                num!(DELTA_TIME),
+                &PEG_CONFIG,
+                &mut rng,
+            )
+            .expect("peg step failed");
+        samples.extend(
+            positions
+                .iter()
+                .zip(&velocities)
+                .map(|(&position, &velocity)| sample(position, velocity)),
+        );
+    }
+    samples
+}
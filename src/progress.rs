@@ -0,0 +1,176 @@
+use agb::save::SaveManager;
+use heapless::Vec;
+
+use crate::ascension::Ascension;
+use crate::error::Error;
+use crate::history::{self, Ending, History, RunRecord};
+use crate::save::{BallKind, Loadout, OwnedBall, Save};
+
+const MAGIC: [u8; 4] = *b"PGL2";
+const SIZE: usize = 24;
+const TOTAL_SIZE: usize = SIZE + history::SIZE;
+
+/// Lifetime levels cleared to unlock the Doubles loadout and the Red Giant.
+const VETERAN_LEVELS: u32 = 10;
+/// Depth to reach once to unlock the Grabbers loadout.
+const DEEP_DEPTH: u32 = 10;
+
+/// Totals over every run.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub runs: u32,
+    pub wins: u32,
+    pub levels_cleared: u32,
+    pub best_depth: u32,
+}
+
+/// What carries over between runs, kept in SRAM. The run itself is `Save`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Progress {
+    unlocked: Ascension,
+    /// One bit per `BallKind` the player ever had in the bag.
+    discovered: u8,
+    stats: Stats,
+    history: History,
+}
+
+const _: () = assert!(BallKind::ALL.len() <= 8, "`discovered` is one byte");
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    let mut word = [0; 4];
+    word.copy_from_slice(&bytes[offset..offset + 4]);
+    u32::from_le_bytes(word)
+}
+
+fn write_u32(bytes: &mut [u8], offset: usize, value: u32) {
+    bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+impl Progress {
+    /// Reads the progress back, starting fresh when SRAM holds none.
+    pub fn load(saves: &mut SaveManager) -> Self {
+        let mut bytes = [0; TOTAL_SIZE];
+        if saves
+            .access()
+            .and_then(|mut data| data.read(0, &mut bytes))
+            .is_err()
+        {
+            return Self::default();
+        }
+
+        let mut progress = Self::from_bytes(&bytes).unwrap_or_default();
+        progress.history = History::from_bytes(&bytes[SIZE..]);
+        progress
+    }
+
+    pub fn store(&self, saves: &mut SaveManager) -> Result<(), Error> {
+        let mut data = saves.access().map_err(|_| Error::Storage)?;
+        let mut block = data
+            .prepare_write(0..TOTAL_SIZE)
+            .map_err(|_| Error::Storage)?;
+        block
+            .write_and_verify(0, &self.to_bytes())
+            .and_then(|()| {
+                block.write_and_verify(SIZE, &self.history.to_bytes())
+            })
+            .map_err(|_| Error::Storage)
+    }
+
+    fn to_bytes(&self) -> [u8; SIZE] {
+        let mut bytes = [0; SIZE];
+        bytes[..4].copy_from_slice(&MAGIC);
+        bytes[4] = self.unlocked.tier();
+        bytes[5] = self.discovered;
+        write_u32(&mut bytes, 8, self.stats.runs);
+        write_u32(&mut bytes, 12, self.stats.wins);
+        write_u32(&mut bytes, 16, self.stats.levels_cleared);
+        write_u32(&mut bytes, 20, self.stats.best_depth);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        (bytes[..4] == MAGIC).then(|| Self {
+            unlocked: Ascension::new(bytes[4]),
+            discovered: bytes[5],
+            stats: Stats {
+                runs: read_u32(bytes, 8),
+                wins: read_u32(bytes, 12),
+                levels_cleared: read_u32(bytes, 16),
+                best_depth: read_u32(bytes, 20),
+            },
+            history: History::default(),
+        })
+    }
+
+    /// Highest ascension the player may pick.
+    pub fn unlocked(&self) -> Ascension {
+        self.unlocked
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    pub fn record_level(&mut self, depth: i32) {
+        self.stats.levels_cleared += 1;
+        self.stats.best_depth = self.stats.best_depth.max(depth as u32);
+    }
+
+    /// Beating the game opens the next ascension.
+    pub fn record_win(&mut self, save: &Save) {
+        self.stats.runs += 1;
+        self.stats.wins += 1;
+        if save.ascension().next() > self.unlocked {
+            self.unlocked = save.ascension().next();
+        }
+        self.history.push(RunRecord::new(save, Ending::Won));
+    }
+
+    pub fn record_loss(&mut self, save: &Save) {
+        self.stats.runs += 1;
+        self.history.push(RunRecord::new(save, Ending::OutOfBalls));
+    }
+
+    pub fn discover(&mut self, balls: &[OwnedBall]) {
+        for ball in balls {
+            self.discovered |= 1 << ball.kind as u8;
+        }
+    }
+
+    pub fn is_discovered(&self, kind: BallKind) -> bool {
+        self.discovered & (1 << kind as u8) != 0
+    }
+
+    /// What unlocks `kind` for drops, `None` once it can drop.
+    pub fn requirement(&self, kind: BallKind) -> Option<&'static str> {
+        match kind {
+            BallKind::RedGiant
+                if self.stats.levels_cleared < VETERAN_LEVELS =>
+            {
+                Some("Clear 10 levels.")
+            }
+            BallKind::TheCrown if self.stats.wins == 0 => Some("Win a run."),
+            _ => None,
+        }
+    }
+
+    /// Balls drops may offer in the next run.
+    pub fn pool(&self) -> Vec<BallKind, { BallKind::NB_DROPPABLES }> {
+        BallKind::DROPPABLES
+            .into_iter()
+            .filter(|&kind| self.requirement(kind).is_none())
+            .collect()
+    }
+
+    pub fn has_loadout(&self, loadout: Loadout) -> bool {
+        match loadout {
+            Loadout::Classic => true,
+            Loadout::Doubles => self.stats.levels_cleared >= VETERAN_LEVELS,
+            Loadout::Grabbers => self.stats.best_depth >= DEEP_DEPTH,
+        }
+    }
+}
@@ -1,62 +1,283 @@
-use agb::{println, rng::RandomNumberGenerator};
+use agb::rng::RandomNumberGenerator;
+use const_random::const_random;
 use heapless::Vec;
 
+use crate::ascension::Ascension;
+use crate::error::Error;
+use crate::loot::Rarity;
+use crate::number::Number;
+use crate::scenes::game::effect::BucketEffect;
+
+pub const MAX_INVENTORY: usize = 10;
+const STARTING_CAPACITY: usize = 7;
+const CAPACITY_UPGRADE_COST: i32 = 5;
+pub const MAX_BUCKET_EFFECTS: usize = 4;
+pub const MAX_RELICS: usize = 6;
+
+/// Three identical balls merge into one of the next tier when set.
+const MERGE_DUPLICATES: bool = true;
+const MERGE_COUNT: usize = 3;
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum BallKind {
     Identity = 0,
     TheDoubler,
     SmallGrabber,
+    BigGrabber,
+    RedGiant,
+    TheCrown,
 }
 
 impl BallKind {
-    pub const NB_DROPPABLES: usize = 2;
-    const DROPPABLES: [BallKind; BallKind::NB_DROPPABLES] =
-        [BallKind::TheDoubler, BallKind::SmallGrabber];
+    pub const NB_DROPPABLES: usize = 5;
+    pub const DROPPABLES: [BallKind; BallKind::NB_DROPPABLES] = [
+        BallKind::TheDoubler,
+        BallKind::SmallGrabber,
+        BallKind::BigGrabber,
+        BallKind::RedGiant,
+        BallKind::TheCrown,
+    ];
 
-    pub fn sprite(&self) -> usize {
+    pub const ALL: [BallKind; BallKind::NB_DROPPABLES + 1] = [
+        BallKind::Identity,
+        BallKind::TheDoubler,
+        BallKind::SmallGrabber,
+        BallKind::BigGrabber,
+        BallKind::RedGiant,
+        BallKind::TheCrown,
+    ];
+
+    pub fn rarity(&self) -> Rarity {
         match self {
-            BallKind::Identity => 0,
-            BallKind::TheDoubler => 0,
-            BallKind::SmallGrabber => 0,
+            BallKind::Identity => Rarity::Common,
+            BallKind::TheDoubler => Rarity::Common,
+            BallKind::SmallGrabber => Rarity::Common,
+            BallKind::BigGrabber => Rarity::Uncommon,
+            BallKind::RedGiant => Rarity::Rare,
+            BallKind::TheCrown => Rarity::Legendary,
         }
     }
 
-    pub fn description(&self) -> &str {
+    pub const fn sprite(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn name(&self) -> &'static str {
         match self {
-            BallKind::Identity => "just a ball...",
+            BallKind::Identity => "Ball",
             BallKind::TheDoubler => "The Doubler",
             BallKind::SmallGrabber => "The Small Grabber",
+            BallKind::BigGrabber => "The Big Grabber",
+            BallKind::RedGiant => "Red Giant",
+            BallKind::TheCrown => "The Crown",
+        }
+    }
+
+    pub fn flavour(&self) -> &'static str {
+        match self {
+            BallKind::Identity => "just a ball...",
+            BallKind::TheDoubler => "Sees red, twice.",
+            BallKind::SmallGrabber => "Small hands, deep pockets.",
+            BallKind::BigGrabber => "Bigger hands, deeper pockets.",
+            BallKind::RedGiant => "Everything looks red to it.",
+            BallKind::TheCrown => "Heavy is the ball.",
+        }
+    }
+}
+
+/// Run-wide items evaluated in the order they are held.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RelicKind {
+    Whetstone = 0,
+    RubyLens,
+    Bellows,
+    TuningFork,
+    Echo,
+    PiggyBank,
+}
+
+impl RelicKind {
+    pub const NB_RELICS: usize = 6;
+    pub const ALL: [RelicKind; RelicKind::NB_RELICS] = [
+        RelicKind::Whetstone,
+        RelicKind::RubyLens,
+        RelicKind::Bellows,
+        RelicKind::TuningFork,
+        RelicKind::Echo,
+        RelicKind::PiggyBank,
+    ];
+
+    pub fn sprite(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RelicKind::Whetstone => "Whetstone",
+            RelicKind::RubyLens => "Ruby Lens",
+            RelicKind::Bellows => "Bellows",
+            RelicKind::TuningFork => "Tuning Fork",
+            RelicKind::Echo => "Echo",
+            RelicKind::PiggyBank => "Piggy Bank",
+        }
+    }
+
+    pub fn flavour(&self) -> &'static str {
+        match self {
+            RelicKind::Whetstone => "Keeps the blues sharp.",
+            RelicKind::RubyLens => "Rose-tinted, mostly.",
+            RelicKind::Bellows => "Fans the bucket.",
+            RelicKind::TuningFork => "Always on the last note.",
+            RelicKind::Echo => "Says it twice.",
+            RelicKind::PiggyBank => "Oink.",
+        }
+    }
+}
+
+/// Balls a run starts with.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Loadout {
+    #[default]
+    Classic,
+    Doubles,
+    Grabbers,
+}
+
+impl Loadout {
+    pub const ALL: [Loadout; 3] =
+        [Loadout::Classic, Loadout::Doubles, Loadout::Grabbers];
+
+    pub fn balls(self) -> &'static [BallKind] {
+        use BallKind::*;
+        match self {
+            Loadout::Classic => &[Identity; 5],
+            Loadout::Doubles => {
+                &[Identity, Identity, Identity, TheDoubler, TheDoubler]
+            }
+            Loadout::Grabbers => {
+                &[Identity, Identity, Identity, SmallGrabber, BigGrabber]
+            }
         }
     }
 
-    pub fn rand(rng: &mut RandomNumberGenerator) -> BallKind {
-        let v = rng.next_i32() as usize;
-        BallKind::DROPPABLES[v % BallKind::NB_DROPPABLES]
+    pub fn name(self) -> &'static str {
+        match self {
+            Loadout::Classic => "Classic",
+            Loadout::Doubles => "Doubles",
+            Loadout::Grabbers => "Grabbers",
+        }
     }
 }
 
+/// A ball in the bag, with the tier it reached through merges.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OwnedBall {
+    pub kind: BallKind,
+    pub tier: u8,
+}
+
+impl OwnedBall {
+    pub const MAX_TIER: u8 = 3;
+
+    pub fn new(kind: BallKind) -> Self {
+        Self { kind, tier: 1 }
+    }
+
+    pub fn can_upgrade(&self) -> bool {
+        self.kind != BallKind::Identity && self.tier < Self::MAX_TIER
+    }
+}
+
+/// How the last played level went, shown on the drop screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LevelResult {
+    pub damage: Number,
+    pub target: i32,
+    pub coins: i32,
+    pub best_shot: Number,
+    pub pegs_hit: u32,
+}
+
 pub struct Save {
-    inventory: Vec<BallKind, 10>,
+    inventory: Vec<OwnedBall, MAX_INVENTORY>,
+    capacity: usize,
+    bucket_effects: Vec<BucketEffect, MAX_BUCKET_EFFECTS>,
+    relics: Vec<RelicKind, MAX_RELICS>,
     coins: i32,
     level: i32,
+    pity: u32,
+    last_result: Option<LevelResult>,
+    best_shot: Number,
+    pegs_hit: u32,
+    seed: u32,
+    rng: RandomNumberGenerator,
+    ascension: Ascension,
+    loadout: Loadout,
+    pool: Vec<BallKind, { BallKind::NB_DROPPABLES }>,
 }
 
 impl Save {
     pub fn new() -> Self {
-        let mut vec = Vec::new();
-        let _ = vec.push(BallKind::Identity);
-        let _ = vec.push(BallKind::Identity);
-        let _ = vec.push(BallKind::Identity);
-        let _ = vec.push(BallKind::Identity);
-        let _ = vec.push(BallKind::Identity);
+        Self::new_run(
+            Ascension::default(),
+            Loadout::default(),
+            &BallKind::DROPPABLES,
+            const_random!(u32),
+        )
+    }
+
+    /// A fresh run. `pool` is the balls drops may offer.
+    pub fn new_run(
+        ascension: Ascension,
+        loadout: Loadout,
+        pool: &[BallKind],
+        seed: u32,
+    ) -> Self {
+        let balls = loadout.balls();
+        let count = balls.len() - ascension.removed_starting_balls();
+        let vec = balls[..count].iter().copied().map(OwnedBall::new).collect();
 
         Self {
             inventory: vec,
+            capacity: STARTING_CAPACITY,
+            bucket_effects: Vec::new(),
+            relics: Vec::new(),
             coins: 0,
             level: 1,
+            pity: 0,
+            last_result: None,
+            best_shot: Number::ZERO,
+            pegs_hit: 0,
+            seed,
+            rng: RandomNumberGenerator::new_with_seed([
+                seed.max(1),
+                const_random!(u32),
+                const_random!(u32),
+                const_random!(u32),
+            ]),
+            ascension,
+            loadout,
+            pool: pool.iter().copied().collect(),
         }
     }
 
+    /// What the run randomness started from, kept for the run history.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    pub fn ascension(&self) -> Ascension {
+        self.ascension
+    }
+
+    pub fn loadout(&self) -> Loadout {
+        self.loadout
+    }
+
+    pub fn pool(&self) -> &[BallKind] {
+        &self.pool
+    }
+
     pub fn level(&self) -> i32 {
         self.level
     }
@@ -65,17 +286,181 @@ impl Save {
         self.level += 1;
     }
 
-    pub fn push_ball(&mut self, ball: BallKind) {
-        self.inventory.push(ball).unwrap_or_else(|_| {
-            println!("Full inventory");
-        });
+    /// Drops in a row without a rare ball, see `loot::roll_bench`.
+    pub fn pity(&self) -> u32 {
+        self.pity
     }
 
-    pub fn inventory(&self) -> &Vec<BallKind, 10> {
+    pub fn set_pity(&mut self, pity: u32) {
+        self.pity = pity;
+    }
+
+    /// Randomness that belongs to the run rather than to a single scene.
+    pub fn rng(&mut self) -> &mut RandomNumberGenerator {
+        &mut self.rng
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.inventory.len() >= self.capacity
+    }
+
+    /// Coins needed for the next bag slot, `None` once the bag cannot grow.
+    pub fn capacity_upgrade_cost(&self) -> Option<i32> {
+        (self.capacity < MAX_INVENTORY).then(|| {
+            CAPACITY_UPGRADE_COST
+                * (self.capacity - STARTING_CAPACITY + 1) as i32
+        })
+    }
+
+    pub fn upgrade_capacity(&mut self) -> Result<(), Error> {
+        let cost = self.capacity_upgrade_cost().ok_or(Error::InventoryFull)?;
+        if self.coins < cost {
+            return Err(Error::NotEnoughCoins);
+        }
+
+        self.coins -= cost;
+        self.capacity += 1;
+        Ok(())
+    }
+
+    fn merges(&self, ball: OwnedBall) -> bool {
+        MERGE_DUPLICATES
+            && ball.can_upgrade()
+            && self.inventory.iter().filter(|b| **b == ball).count()
+                >= MERGE_COUNT - 1
+    }
+
+    fn insert(&mut self, mut ball: OwnedBall) -> OwnedBall {
+        while self.merges(ball) {
+            for _ in 1..MERGE_COUNT {
+                if let Some(i) = self.inventory.iter().position(|b| *b == ball)
+                {
+                    self.inventory.remove(i);
+                }
+            }
+            ball.tier += 1;
+        }
+
+        let _ = self.inventory.push(ball);
+        ball
+    }
+
+    /// Adds a new ball, merging it with its duplicates, which works even in
+    /// a full bag. Returns the ball that ended up in the bag.
+    pub fn push_ball(&mut self, kind: BallKind) -> Result<OwnedBall, Error> {
+        let ball = OwnedBall::new(kind);
+        if self.is_full() && !self.merges(ball) {
+            return Err(Error::InventoryFull);
+        }
+
+        Ok(self.insert(ball))
+    }
+
+    pub fn replace_ball(
+        &mut self,
+        index: usize,
+        kind: BallKind,
+    ) -> Result<OwnedBall, Error> {
+        if index >= self.inventory.len() {
+            return Err(Error::IndexTooBig);
+        }
+
+        self.inventory.remove(index);
+        Ok(self.insert(OwnedBall::new(kind)))
+    }
+
+    pub fn remove_ball(&mut self, index: usize) -> Result<OwnedBall, Error> {
+        if index >= self.inventory.len() {
+            return Err(Error::IndexTooBig);
+        }
+
+        Ok(self.inventory.remove(index))
+    }
+
+    pub fn upgrade_ball(&mut self, index: usize) -> Result<OwnedBall, Error> {
+        let ball = self.inventory.get_mut(index).ok_or(Error::IndexTooBig)?;
+        if ball.can_upgrade() {
+            ball.tier += 1;
+        }
+
+        Ok(*ball)
+    }
+
+    pub fn set_inventory(
+        &mut self,
+        balls: impl IntoIterator<Item = OwnedBall>,
+    ) {
+        self.inventory.clear();
+        for ball in balls.into_iter().take(self.capacity) {
+            let _ = self.inventory.push(ball);
+        }
+    }
+
+    pub fn inventory(&self) -> &Vec<OwnedBall, MAX_INVENTORY> {
         &self.inventory
     }
 
     pub fn coins(&self) -> i32 {
         self.coins
     }
+
+    pub fn set_coins(&mut self, coins: i32) {
+        self.coins = coins;
+    }
+
+    pub fn add_coins(&mut self, coins: i32) {
+        self.coins += coins;
+    }
+
+    pub fn bucket_effects(&self) -> &Vec<BucketEffect, MAX_BUCKET_EFFECTS> {
+        &self.bucket_effects
+    }
+
+    pub fn push_bucket_effect(
+        &mut self,
+        effect: BucketEffect,
+    ) -> Result<(), Error> {
+        self.bucket_effects
+            .push(effect)
+            .map_err(|_| Error::InventoryFull)
+    }
+
+    pub fn relics(&self) -> &Vec<RelicKind, MAX_RELICS> {
+        &self.relics
+    }
+
+    pub fn push_relic(&mut self, relic: RelicKind) -> Result<(), Error> {
+        self.relics.push(relic).map_err(|_| Error::InventoryFull)
+    }
+
+    /// Relics are kept in the order given, which is their evaluation order.
+    pub fn set_relics(&mut self, relics: impl IntoIterator<Item = RelicKind>) {
+        self.relics.clear();
+        for relic in relics {
+            let _ = self.relics.push(relic);
+        }
+    }
+
+    pub fn last_result(&self) -> Option<LevelResult> {
+        self.last_result
+    }
+
+    /// Keeps `result` for the drop screen and adds it to the run totals.
+    pub fn record_result(&mut self, result: LevelResult) {
+        self.best_shot = self.best_shot.max(result.best_shot);
+        self.pegs_hit = self.pegs_hit.saturating_add(result.pegs_hit);
+        self.last_result = Some(result);
+    }
+
+    pub fn best_shot(&self) -> Number {
+        self.best_shot
+    }
+
+    pub fn pegs_hit(&self) -> u32 {
+        self.pegs_hit
+    }
 }
@@ -0,0 +1,142 @@
+use super::text_box::TextBox;
+use agb::display::object::Object;
+use agb::display::tiled::{
+    RegularBackground, RegularBackgroundSize, TileFormat,
+};
+use agb::fixnum::{Vector2D, vec2};
+use agb::include_aseprite;
+use agb::input::{Button, ButtonController};
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::Error;
+use crate::progress::Progress;
+use crate::save::BallKind;
+use crate::scenes::Scene;
+use crate::scenes::game::effect::BallData;
+use crate::scenes::panel;
+use crate::scenes::transition::{Kind, Transition};
+
+include_aseprite!(
+    mod sprites,
+    "assets/ball.aseprite",
+    "assets/ball_cursor.aseprite",
+);
+
+const TEXT_REVEAL_SPEED: usize = 2;
+
+// Panels, in tiles
+const HEADER_TOP: i32 = 0;
+const HEADER_ROWS: i32 = 4;
+const BODY_TOP: i32 = 4;
+const BODY_ROWS: i32 = 16;
+
+// In pixels
+const HEADER_POS: Vector2D<i32> = vec2(20, 9);
+const HEADER_WIDTH: i32 = 200;
+const HEADER_HEIGHT: i32 = 14;
+const BALLS_X: i32 = 28;
+const BALLS_Y: i32 = 48;
+const BALL_SPACING: i32 = 16;
+const TEXT_POS: Vector2D<i32> = vec2(56, 44);
+const TEXT_WIDTH: i32 = 164;
+const TEXT_HEIGHT: i32 = 100;
+
+fn description(progress: &Progress, kind: BallKind) -> String {
+    let mut text = if progress.is_discovered(kind) {
+        BallData::from_kind(kind).description()
+    } else {
+        String::from("???\nNot discovered yet.")
+    };
+    if let Some(requirement) = progress.requirement(kind) {
+        text.push_str(&format!("\n{{red}}Locked:{{white}} {requirement}"));
+    }
+    text
+}
+
+/// Every ball of the game, and what the player found out about it.
+pub fn main(
+    gba: &mut agb::Gba,
+    progress: &Progress,
+    transition: &mut Transition,
+) -> Result<Scene, Error> {
+    let mut gfx = gba.graphics.get();
+    let mut input = ButtonController::new();
+
+    crate::reset::reset_input(Button::A, &mut input, &mut gfx);
+
+    panel::set_palettes();
+    let mut bg = RegularBackground::new(
+        agb::display::Priority::P0,
+        RegularBackgroundSize::Background32x32,
+        TileFormat::FourBpp,
+    );
+    panel::draw(&mut bg, HEADER_TOP, HEADER_ROWS);
+    panel::draw(&mut bg, BODY_TOP, BODY_ROWS);
+
+    let found = BallKind::ALL
+        .iter()
+        .filter(|&&kind| progress.is_discovered(kind))
+        .count();
+    let mut header = TextBox::new(HEADER_POS, HEADER_WIDTH, HEADER_HEIGHT);
+    header.set_reveal_speed(TEXT_REVEAL_SPEED);
+    header.set_text(&format!(
+        "{{yellow}}Collection{{white}}  {found}/{} found  B: back",
+        BallKind::ALL.len()
+    ));
+
+    let mut selected: usize = 0;
+    let mut text_box = TextBox::new(TEXT_POS, TEXT_WIDTH, TEXT_HEIGHT);
+    text_box.set_reveal_speed(TEXT_REVEAL_SPEED);
+    text_box.set_text(&description(progress, BallKind::ALL[selected]));
+
+    loop {
+        input.update();
+        header.update();
+        text_box.update();
+
+        if let Some(scene) = transition.update() {
+            return Ok(scene);
+        }
+
+        if transition.is_idle() {
+            let previous = selected;
+            if input.is_just_pressed(Button::B) {
+                transition.leave(Kind::FadeToBlack, Scene::Title);
+            } else if input.is_just_pressed(Button::A) {
+                text_box.next_page();
+            } else if input.is_just_pressed(Button::UP) && selected > 0 {
+                selected -= 1;
+            } else if input.is_just_pressed(Button::DOWN)
+                && selected + 1 < BallKind::ALL.len()
+            {
+                selected += 1;
+            }
+
+            if selected != previous {
+                text_box
+                    .set_text(&description(progress, BallKind::ALL[selected]));
+            }
+        }
+
+        let mut frame = gfx.frame();
+        for (i, kind) in BallKind::ALL.iter().enumerate() {
+            let position = vec2(BALLS_X, BALLS_Y + BALL_SPACING * i as i32);
+            if progress.is_discovered(*kind) {
+                Object::new(sprites::BALL.sprite(kind.sprite()))
+                    .set_pos(position)
+                    .show(&mut frame);
+            }
+            if i == selected {
+                Object::new(sprites::CURSOR.sprite(0))
+                    .set_pos(position + vec2(-4, -4))
+                    .show(&mut frame);
+            }
+        }
+        let bg_id = bg.show(&mut frame);
+        header.show(&mut frame);
+        text_box.show(&mut frame);
+        transition.show(&mut frame, &[bg_id.into()]);
+        frame.commit();
+    }
+}
@@ -1,93 +1,300 @@
-use super::text_box::{self, TextBox};
-use agb::display::object::Object;
+use super::text_box::TextBox;
+use agb::display::GraphicsFrame;
+use agb::display::object::{Object, Sprite};
 use agb::display::tiled::{
-    RegularBackground, RegularBackgroundSize, TileFormat, VRAM_MANAGER,
+    RegularBackground, RegularBackgroundSize, TileFormat,
 };
-use agb::fixnum::vec2;
+use agb::fixnum::{Vector2D, vec2};
 use agb::input::{Button, ButtonController};
-use agb::rng::RandomNumberGenerator;
-use agb::{include_aseprite, include_background_gfx, println};
-use const_random::const_random;
+use agb::sound::mixer::{Frequency, SoundChannel, SoundData};
+use agb::{include_aseprite, include_wav};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 use crate::error::Error;
+use crate::level;
 use crate::save::{BallKind, Save};
 use crate::scenes::Scene;
+use crate::scenes::panel;
+use crate::scenes::transition::{Kind, Transition};
 
-include_background_gfx!(
-    mod background,
-    "000000",
-    BACKGROUND => deduplicate "assets/drop_background.aseprite",
-);
+mod reward;
+
+use reward::Reward;
 
 include_aseprite!(
     mod sprites,
-    "assets/drop_ball_cursor.aseprite"
+    "assets/ball.aseprite",
+    "assets/ball_cursor.aseprite",
+    "assets/bucket.aseprite",
+    "assets/icons.aseprite",
+    "assets/relics.aseprite",
 );
 
-const BENCH_SIZE: usize = 2;
+static MUSIC: SoundData = include_wav!("assets/drop_music.wav");
+
+const TEXT_REVEAL_SPEED: usize = 2;
+const SUMMARY_POS: Vector2D<i32> = vec2(8, 2);
+const SUMMARY_WIDTH: i32 = 224;
+const SUMMARY_HEIGHT: i32 = 28;
+const FOOTER_POS: Vector2D<i32> = vec2(8, 146);
+const FOOTER_HEIGHT: i32 = 14;
+
+// Choice boxes, in tiles
+const BOXES_TOP: i32 = 4;
+const BOXES_ROWS: i32 = 14;
+const BOX_MAX_ROWS: i32 = 6;
+
+// Inside a choice box, in pixels
+const ICON_CENTER_X: i32 = 32;
+const TEXT_X: i32 = 52;
+const TEXT_WIDTH: i32 = 172;
+
+const BAG_X: i32 = 36;
+const BAG_Y: i32 = 148;
+const BAG_SPACING: i32 = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Choosing,
+    /// The bag is full: pick a slot to give up for the chosen ball.
+    Replacing(BallKind, usize),
+}
+
+/// Top row and height of every box, in tiles. Boxes are spaced by a row
+/// while they fit, and stacked otherwise.
+fn box_rows(count: usize) -> Vec<(i32, i32)> {
+    let count = count.max(1) as i32;
+    let gap = if count * 3 + count - 1 <= BOXES_ROWS {
+        1
+    } else {
+        0
+    };
+    let height = ((BOXES_ROWS - gap * (count - 1)) / count).min(BOX_MAX_ROWS);
+    let used = height * count + gap * (count - 1);
+    let top = BOXES_TOP + (BOXES_ROWS - used) / 2;
+
+    (0..count)
+        .map(|i| (top + i * (height + gap), height))
+        .collect()
+}
+
+fn icon(reward: Reward) -> &'static Sprite {
+    match reward {
+        Reward::Ball(kind) => sprites::BALL.sprite(kind.sprite()),
+        Reward::Bucket(_) => sprites::BUCKET.sprite(0),
+        Reward::Relic(relic) => sprites::RELIC.sprite(relic.sprite()),
+        Reward::Coins(_) => sprites::COIN.sprite(0),
+    }
+}
+
+fn summary_text(save: &Save, depth: i32) -> String {
+    let mut text = format!("{{yellow}}Level {depth} cleared!{{white}}");
+    if let Some(result) = save.last_result() {
+        text.push_str(&format!(
+            "\n{}/{} damage  {:+}[coin]",
+            result.damage, result.target, result.coins
+        ));
+    }
+    text
+}
+
+fn prompt_text(save: &Save) -> String {
+    let mut text = format!(
+        "Bag full ({}/{})! A: swap  B: back",
+        save.inventory().len(),
+        save.capacity()
+    );
+    if let Some(cost) = save.capacity_upgrade_cost() {
+        text.push_str(&format!(
+            "\nR: bigger bag for {cost}[coin] (have {})",
+            save.coins()
+        ));
+    }
+    text
+}
+
+fn show_bag(frame: &mut GraphicsFrame, save: &Save, selected: usize) {
+    for (i, ball) in save.inventory().iter().enumerate() {
+        let position = vec2(BAG_X + BAG_SPACING * i as i32, BAG_Y);
+        Object::new(sprites::BALL.sprite(ball.kind.sprite()))
+            .set_pos(position)
+            .show(frame);
+
+        if i == selected {
+            Object::new(sprites::CURSOR.sprite(0))
+                .set_pos(position + vec2(-4, -4))
+                .show(frame);
+        }
+    }
+}
 
-pub fn main(gba: &mut agb::Gba, save: &mut Save) -> Result<Scene, Error> {
+pub fn main(
+    gba: &mut agb::Gba,
+    save: &mut Save,
+    transition: &mut Transition,
+) -> Result<Scene, Error> {
     let mut gfx = gba.graphics.get();
+    let mut mixer = gba.mixer.mixer(Frequency::Hz10512);
     let mut input = ButtonController::new();
 
+    let depth = save.level();
     save.increase_level();
+    let next = if level::has_event(save.level()) {
+        Scene::Event
+    } else {
+        Scene::Game
+    };
 
     crate::reset::reset_input(Button::A, &mut input, &mut gfx);
 
-    VRAM_MANAGER.set_background_palettes(background::PALETTES);
+    let mut music = SoundChannel::new_high_priority(MUSIC);
+    music.should_loop();
+    let _ = mixer.play_sound(music);
+
+    panel::set_palettes();
     let mut bg = RegularBackground::new(
         agb::display::Priority::P0,
         RegularBackgroundSize::Background32x32,
         TileFormat::FourBpp,
     );
-    bg.fill_with(&background::BACKGROUND);
 
-    let mut rng = RandomNumberGenerator::new_with_seed([
-        const_random!(u32),
-        const_random!(u32),
-        const_random!(u32),
-        const_random!(u32),
-    ]);
+    let rewards = reward::roll(save, depth);
+    let rows = box_rows(rewards.len());
 
-    let bench: [BallKind; BENCH_SIZE] =
-        [BallKind::rand(&mut rng), BallKind::rand(&mut rng)];
-    let mut selector: usize = 0;
+    let mut icons = Vec::new();
+    let mut text_boxes = Vec::new();
+    let mut cursor_positions = Vec::new();
+    for (reward, &(top, height)) in rewards.iter().zip(rows.iter()) {
+        panel::draw(&mut bg, top, height);
 
-    let mut up_text_box = TextBox::new(vec2(66, 52), 138);
-    up_text_box.set_text(bench[0].description());
+        let sprite = icon(*reward);
+        let center = vec2(ICON_CENTER_X, top * 8 + height * 4);
+        let mut object = Object::new(sprite);
+        let (width, height_px) = sprite.size().to_width_height();
+        object.set_pos(center - vec2(width as i32, height_px as i32) / 2);
+        icons.push(object);
+        cursor_positions.push(center - vec2(8, 8));
 
-    let mut down_text_box = TextBox::new(vec2(66, 100), 138);
-    down_text_box.set_text(bench[1].description());
+        let mut text_box =
+            TextBox::new(vec2(TEXT_X, top * 8 + 3), TEXT_WIDTH, height * 8 - 6);
+        text_box.set_reveal_speed(TEXT_REVEAL_SPEED);
+        text_box.set_text(&reward.description());
+        text_boxes.push(text_box);
+    }
 
-    let mut selector_sprite = Object::new(sprites::CURSOR.sprite(0));
-    selector_sprite.set_pos(vec2(36, 59));
+    let mut summary = TextBox::new(SUMMARY_POS, SUMMARY_WIDTH, SUMMARY_HEIGHT);
+    summary.set_reveal_speed(TEXT_REVEAL_SPEED);
+    summary.set_text(&summary_text(save, depth));
+
+    let skip_coins = reward::skip_coins(depth);
+    let mut footer = TextBox::new(FOOTER_POS, SUMMARY_WIDTH, FOOTER_HEIGHT);
+    footer.set_reveal_speed(TEXT_REVEAL_SPEED);
+    footer.set_text(&format!("A: take  B: skip for {skip_coins:+}[coin]"));
+
+    let mut selector: usize = 0;
+    let mut mode = Mode::Choosing;
 
     loop {
         input.update();
 
-        up_text_box.update();
-        down_text_box.update();
-
-        if input.is_just_pressed(Button::A) {
-            save.push_ball(bench[selector]);
-            println!("pushed {:?}", bench[selector]);
-            return Ok(Scene::Game);
+        for text_box in text_boxes.iter_mut() {
+            text_box.update();
         }
+        summary.update();
+        footer.update();
 
-        if input.is_just_pressed(Button::UP) && selector > 0 {
-            selector -= 1;
-            selector_sprite.set_pos(vec2(36, 59));
+        if let Some(scene) = transition.update() {
+            return Ok(scene);
         }
-        if input.is_just_pressed(Button::DOWN) && selector < BENCH_SIZE - 1 {
-            selector += 1;
-            selector_sprite.set_pos(vec2(36, 107));
+
+        match mode {
+            _ if !transition.is_idle() => {}
+            Mode::Choosing => {
+                if input.is_just_pressed(Button::A)
+                    && !text_boxes[selector].next_page()
+                {
+                    match rewards[selector] {
+                        Reward::Ball(kind) => match save.push_ball(kind) {
+                            Ok(_) => transition.leave(Kind::FadeToBlack, next),
+                            Err(Error::InventoryFull) => {
+                                summary.set_text(&prompt_text(save));
+                                footer.remove();
+                                mode = Mode::Replacing(kind, 0);
+                            }
+                            Err(err) => return Err(err),
+                        },
+                        Reward::Bucket(effect) => {
+                            save.push_bucket_effect(effect)?;
+                            transition.leave(Kind::FadeToBlack, next);
+                        }
+                        Reward::Relic(relic) => {
+                            save.push_relic(relic)?;
+                            transition.leave(Kind::FadeToBlack, next);
+                        }
+                        Reward::Coins(coins) => {
+                            save.add_coins(coins);
+                            transition.leave(Kind::FadeToBlack, next);
+                        }
+                    }
+                } else if input.is_just_pressed(Button::B) {
+                    save.add_coins(skip_coins);
+                    transition.leave(Kind::FadeToBlack, next);
+                }
+
+                if input.is_just_pressed(Button::UP) && selector > 0 {
+                    selector -= 1;
+                }
+                if input.is_just_pressed(Button::DOWN)
+                    && selector + 1 < rewards.len()
+                {
+                    selector += 1;
+                }
+            }
+            Mode::Replacing(kind, slot) => {
+                if input.is_just_pressed(Button::A) {
+                    save.replace_ball(slot, kind)?;
+                    transition.leave(Kind::FadeToBlack, next);
+                } else if input.is_just_pressed(Button::B) {
+                    summary.set_text(&summary_text(save, depth));
+                    footer.set_text(&format!(
+                        "A: take  B: skip for {skip_coins:+}[coin]"
+                    ));
+                    mode = Mode::Choosing;
+                } else if input.is_just_pressed(Button::R)
+                    && save.upgrade_capacity().is_ok()
+                {
+                    save.push_ball(kind)?;
+                    transition.leave(Kind::FadeToBlack, next);
+                } else if input.is_just_pressed(Button::LEFT) && slot > 0 {
+                    mode = Mode::Replacing(kind, slot - 1);
+                } else if input.is_just_pressed(Button::RIGHT)
+                    && slot + 1 < save.inventory().len()
+                {
+                    mode = Mode::Replacing(kind, slot + 1);
+                }
+            }
         }
 
         let mut frame = gfx.frame();
-        selector_sprite.show(&mut frame);
-        bg.show(&mut frame);
-        up_text_box.show(&mut frame);
-        down_text_box.show(&mut frame);
+        Object::new(sprites::CURSOR.sprite(0))
+            .set_pos(cursor_positions[selector])
+            .show(&mut frame);
+        for icon in icons.iter() {
+            icon.show(&mut frame);
+        }
+        if let Mode::Replacing(_, slot) = mode {
+            show_bag(&mut frame, save, slot);
+        }
+        let bg_id = bg.show(&mut frame);
+        for text_box in text_boxes.iter_mut() {
+            text_box.show(&mut frame);
+        }
+        summary.show(&mut frame);
+        footer.show(&mut frame);
+        transition.show(&mut frame, &[bg_id.into()]);
+        mixer.frame();
         frame.commit();
     }
 }
@@ -0,0 +1,74 @@
+use alloc::format;
+use alloc::string::String;
+use heapless::Vec;
+
+use crate::level::Node;
+use crate::loot;
+use crate::save::{BallKind, RelicKind, Save};
+use crate::scenes::game::effect::{BallData, BucketEffect};
+
+pub const MAX_CHOICES: usize = 4;
+
+const BUCKET_DROPS: [BucketEffect; 3] = [
+    BucketEffect::MultiplyMult(2),
+    BucketEffect::AddBase(20),
+    BucketEffect::AddCoins(3),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Reward {
+    Ball(BallKind),
+    Bucket(BucketEffect),
+    Relic(RelicKind),
+    Coins(i32),
+}
+
+impl Reward {
+    pub fn description(self) -> String {
+        match self {
+            Reward::Ball(kind) => BallData::from_kind(kind).description(),
+            Reward::Bucket(effect) => effect.description(),
+            Reward::Relic(relic) => relic.description(),
+            Reward::Coins(coins) => format!(
+                "{{yellow}}Purse{{white}}\n{coins:+} [coin]\nFor a bigger bag."
+            ),
+        }
+    }
+}
+
+pub fn skip_coins(depth: i32) -> i32 {
+    1 + depth / 2
+}
+
+fn purse_coins(depth: i32) -> i32 {
+    4 + depth
+}
+
+/// Battles offer two balls and a purse, elites add a bucket effect and bosses
+/// swap the purse for a relic.
+pub fn roll(save: &mut Save, depth: i32) -> Vec<Reward, MAX_CHOICES> {
+    let node = Node::at_depth(depth);
+    let mut pity = save.pity();
+    let pool = save.pool().to_vec();
+    let balls =
+        loot::roll_bench::<2>(save.rng(), depth, node, &mut pity, &pool);
+    save.set_pity(pity);
+
+    let mut rewards: Vec<Reward, MAX_CHOICES> =
+        balls.into_iter().map(Reward::Ball).collect();
+
+    if node != Node::Battle && !save.bucket_effects().is_full() {
+        let index = loot::below(save.rng(), BUCKET_DROPS.len() as u32) as usize;
+        let _ = rewards.push(Reward::Bucket(BUCKET_DROPS[index]));
+    }
+    if node != Node::Boss {
+        let _ = rewards.push(Reward::Coins(purse_coins(depth)));
+    } else {
+        let held = save.relics().clone();
+        if let Some(relic) = loot::new_relic(save.rng(), &held) {
+            let _ = rewards.push(Reward::Relic(relic));
+        }
+    }
+
+    rewards
+}
@@ -0,0 +1,169 @@
+use alloc::format;
+use alloc::string::String;
+use heapless::Vec;
+
+use crate::error::Error;
+use crate::loot;
+use crate::save::{MAX_INVENTORY, Save};
+
+/// A stop between fights: some story and a few ways to react to it.
+pub struct Event {
+    pub title: &'static str,
+    pub story: &'static str,
+    pub choices: &'static [Choice],
+}
+
+pub struct Choice {
+    pub label: &'static str,
+    pub cost: Cost,
+    pub outcome: Outcome,
+}
+
+/// What the player gives up to take a choice.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cost {
+    Free,
+    Coins(i32),
+    /// A random ball from the bag. The last ball is never taken.
+    Ball,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    Nothing,
+    Coins(i32),
+    /// `chance` in a hundred to win `prize` coins.
+    Gamble {
+        chance: u32,
+        prize: i32,
+    },
+    /// One more tier for a random ball that can still grow.
+    UpgradeBall,
+    Relic,
+}
+
+impl Cost {
+    fn can_pay(self, save: &Save) -> bool {
+        match self {
+            Cost::Free => true,
+            Cost::Coins(coins) => save.coins() >= coins,
+            Cost::Ball => save.inventory().len() > 1,
+        }
+    }
+
+    fn pay(self, save: &mut Save) -> Result<String, Error> {
+        match self {
+            Cost::Free => Ok(String::new()),
+            Cost::Coins(coins) => {
+                save.add_coins(-coins);
+                Ok(format!("{:+}[coin] ", -coins))
+            }
+            Cost::Ball => {
+                let count = save.inventory().len() as u32;
+                let index = loot::below(save.rng(), count) as usize;
+                let ball = save.remove_ball(index)?;
+                Ok(format!("Lost {}. ", ball.kind.name()))
+            }
+        }
+    }
+
+    fn description(self) -> String {
+        match self {
+            Cost::Free => String::new(),
+            Cost::Coins(coins) => format!("{coins}[coin]: "),
+            Cost::Ball => String::from("Lose a random ball: "),
+        }
+    }
+}
+
+impl Outcome {
+    fn can_happen(self, save: &Save) -> bool {
+        match self {
+            Outcome::UpgradeBall => {
+                save.inventory().iter().any(|ball| ball.can_upgrade())
+            }
+            Outcome::Relic => !save.relics().is_full(),
+            _ => true,
+        }
+    }
+
+    fn apply(self, save: &mut Save) -> Result<String, Error> {
+        match self {
+            Outcome::Nothing => Ok(String::from("Nothing happens.")),
+            Outcome::Coins(coins) => {
+                save.add_coins(coins);
+                Ok(format!("{coins:+}[coin]"))
+            }
+            Outcome::Gamble { chance, prize } => {
+                if loot::below(save.rng(), 100) < chance {
+                    save.add_coins(prize);
+                    Ok(format!("{{yellow}}Won!{{white}} {prize:+}[coin]"))
+                } else {
+                    Ok(String::from("{red}Lost.{white}"))
+                }
+            }
+            Outcome::UpgradeBall => {
+                let upgradable: Vec<usize, MAX_INVENTORY> = save
+                    .inventory()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, ball)| ball.can_upgrade())
+                    .map(|(i, _)| i)
+                    .collect();
+                if upgradable.is_empty() {
+                    return Ok(String::from("Nothing left to upgrade."));
+                }
+
+                let pick = loot::below(save.rng(), upgradable.len() as u32);
+                let ball = save.upgrade_ball(upgradable[pick as usize])?;
+                Ok(format!("{} is now tier {}.", ball.kind.name(), ball.tier))
+            }
+            Outcome::Relic => {
+                let held = save.relics().clone();
+                let relic = loot::new_relic(save.rng(), &held)
+                    .ok_or(Error::InventoryFull)?;
+                save.push_relic(relic)?;
+                Ok(format!("Got {{yellow}}{}{{white}}.", relic.name()))
+            }
+        }
+    }
+
+    fn description(self) -> String {
+        match self {
+            Outcome::Nothing => String::from("walk away"),
+            Outcome::Coins(coins) => format!("{coins:+}[coin]"),
+            Outcome::Gamble { chance, prize } => {
+                format!("{chance}% chance of {prize:+}[coin]")
+            }
+            Outcome::UpgradeBall => String::from("upgrade a random ball"),
+            Outcome::Relic => String::from("gain a relic"),
+        }
+    }
+}
+
+impl Choice {
+    pub fn is_available(&self, save: &Save) -> bool {
+        self.cost.can_pay(save) && self.outcome.can_happen(save)
+    }
+
+    /// Pays the cost then applies the outcome, returning what happened.
+    pub fn take(&self, save: &mut Save) -> Result<String, Error> {
+        let mut text = self.cost.pay(save)?;
+        text.push_str(&self.outcome.apply(save)?);
+        Ok(text)
+    }
+
+    pub fn description(&self, save: &Save) -> String {
+        let colour = if self.is_available(save) {
+            "white"
+        } else {
+            "red"
+        };
+        format!(
+            "{{{colour}}}{}{{white}}\n{}{}",
+            self.label,
+            self.cost.description(),
+            self.outcome.description()
+        )
+    }
+}
@@ -0,0 +1,84 @@
+use super::choice::{Choice, Cost, Event, Outcome};
+
+/// Every event the run can stop at. New events only need an entry here.
+pub static EVENTS: &[Event] = &[
+    Event {
+        title: "The Pawnshop",
+        story: "A dusty shop with a sign: WE BUY BALLS. The owner eyes your \
+                bag.",
+        choices: &[
+            Choice {
+                label: "Sell a ball",
+                cost: Cost::Ball,
+                outcome: Outcome::Coins(8),
+            },
+            Choice {
+                label: "Leave",
+                cost: Cost::Free,
+                outcome: Outcome::Nothing,
+            },
+        ],
+    },
+    Event {
+        title: "Shell Game",
+        story: "Three cups, one coin, very fast hands. \"Double or nothing, \
+                friend?\"",
+        choices: &[
+            Choice {
+                label: "Play",
+                cost: Cost::Coins(5),
+                outcome: Outcome::Gamble {
+                    chance: 50,
+                    prize: 10,
+                },
+            },
+            Choice {
+                label: "Play big",
+                cost: Cost::Coins(10),
+                outcome: Outcome::Gamble {
+                    chance: 33,
+                    prize: 30,
+                },
+            },
+            Choice {
+                label: "Leave",
+                cost: Cost::Free,
+                outcome: Outcome::Nothing,
+            },
+        ],
+    },
+    Event {
+        title: "The Forge",
+        story: "A smith hammers a ball into shape. \"Yours next? Not for \
+                free.\"",
+        choices: &[
+            Choice {
+                label: "Temper",
+                cost: Cost::Coins(6),
+                outcome: Outcome::UpgradeBall,
+            },
+            Choice {
+                label: "Leave",
+                cost: Cost::Free,
+                outcome: Outcome::Nothing,
+            },
+        ],
+    },
+    Event {
+        title: "Shrine",
+        story: "An old shrine glows softly. Something rests on the altar, \
+                and it wants an offering.",
+        choices: &[
+            Choice {
+                label: "Offer a ball",
+                cost: Cost::Ball,
+                outcome: Outcome::Relic,
+            },
+            Choice {
+                label: "Pray",
+                cost: Cost::Free,
+                outcome: Outcome::Coins(2),
+            },
+        ],
+    },
+];
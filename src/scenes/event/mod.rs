@@ -0,0 +1,162 @@
+use super::text_box::TextBox;
+use agb::display::object::Object;
+use agb::display::tiled::{
+    RegularBackground, RegularBackgroundSize, TileFormat,
+};
+use agb::fixnum::{Vector2D, vec2};
+use agb::include_aseprite;
+use agb::input::{Button, ButtonController};
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::error::Error;
+use crate::loot;
+use crate::save::Save;
+use crate::scenes::Scene;
+use crate::scenes::panel;
+use crate::scenes::transition::{Kind, Transition};
+
+mod choice;
+mod data;
+
+use data::EVENTS;
+
+include_aseprite!(
+    mod sprites,
+    "assets/ball_cursor.aseprite",
+);
+
+const TEXT_REVEAL_SPEED: usize = 2;
+
+// Panels, in tiles
+const STORY_TOP: i32 = 0;
+const STORY_ROWS: i32 = 8;
+const CHOICE_ROWS: i32 = 4;
+
+// Inside a panel, in pixels
+const STORY_POS: Vector2D<i32> = vec2(20, 6);
+const STORY_WIDTH: i32 = 200;
+const CURSOR_X: i32 = 16;
+const TEXT_X: i32 = 36;
+const TEXT_WIDTH: i32 = 184;
+const CHOICE_TEXT_Y: i32 = 2;
+const CHOICE_TEXT_HEIGHT: i32 = CHOICE_ROWS * 8 - 4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Choosing,
+    /// The outcome is on screen, any key moves on.
+    Done,
+}
+
+fn choice_top(index: usize) -> i32 {
+    STORY_TOP + STORY_ROWS + CHOICE_ROWS * index as i32
+}
+
+pub fn main(
+    gba: &mut agb::Gba,
+    save: &mut Save,
+    transition: &mut Transition,
+) -> Result<Scene, Error> {
+    let mut gfx = gba.graphics.get();
+    let mut input = ButtonController::new();
+
+    crate::reset::reset_input(Button::A, &mut input, &mut gfx);
+
+    panel::set_palettes();
+    let mut bg = RegularBackground::new(
+        agb::display::Priority::P0,
+        RegularBackgroundSize::Background32x32,
+        TileFormat::FourBpp,
+    );
+
+    let event = &EVENTS[loot::below(save.rng(), EVENTS.len() as u32) as usize];
+
+    panel::draw(&mut bg, STORY_TOP, STORY_ROWS);
+    let mut story = TextBox::new(STORY_POS, STORY_WIDTH, STORY_ROWS * 8 - 12);
+    story.set_reveal_speed(TEXT_REVEAL_SPEED);
+    story.set_text(&format!(
+        "{{yellow}}{}{{white}}\n{}",
+        event.title, event.story
+    ));
+
+    let mut text_boxes = Vec::new();
+    for (i, choice) in event.choices.iter().enumerate() {
+        let top = choice_top(i);
+        panel::draw(&mut bg, top, CHOICE_ROWS);
+
+        // Two lines: the label, then what it costs and does.
+        let mut text_box = TextBox::new(
+            vec2(TEXT_X, top * 8 + CHOICE_TEXT_Y),
+            TEXT_WIDTH,
+            CHOICE_TEXT_HEIGHT,
+        );
+        text_box.set_reveal_speed(TEXT_REVEAL_SPEED);
+        text_box.set_text(&choice.description(save));
+        text_boxes.push(text_box);
+    }
+
+    let mut selector: usize = 0;
+    let mut mode = Mode::Choosing;
+
+    loop {
+        input.update();
+
+        story.update();
+        for text_box in text_boxes.iter_mut() {
+            text_box.update();
+        }
+
+        if let Some(scene) = transition.update() {
+            return Ok(scene);
+        }
+
+        match mode {
+            _ if !transition.is_idle() => {}
+            _ if input.is_just_pressed(Button::A) && story.next_page() => {}
+            Mode::Choosing => {
+                let choice = &event.choices[selector];
+                if input.is_just_pressed(Button::A) && choice.is_available(save)
+                {
+                    let outcome = choice.take(save)?;
+                    story.set_text(&format!(
+                        "{{yellow}}{}{{white}}\n{outcome}",
+                        choice.label
+                    ));
+                    text_boxes.clear();
+                    mode = Mode::Done;
+                }
+
+                if input.is_just_pressed(Button::UP) && selector > 0 {
+                    selector -= 1;
+                }
+                if input.is_just_pressed(Button::DOWN)
+                    && selector + 1 < event.choices.len()
+                {
+                    selector += 1;
+                }
+            }
+            Mode::Done => {
+                if input.is_just_pressed(Button::A)
+                    || input.is_just_pressed(Button::B)
+                {
+                    transition.leave(Kind::FadeToBlack, Scene::Game);
+                }
+            }
+        }
+
+        let mut frame = gfx.frame();
+        if mode == Mode::Choosing {
+            Object::new(sprites::CURSOR.sprite(0))
+                .set_pos(vec2(CURSOR_X, choice_top(selector) * 8 + 8))
+                .show(&mut frame);
+        }
+        let bg_id = bg.show(&mut frame);
+        story.show(&mut frame);
+        for text_box in text_boxes.iter_mut() {
+            text_box.show(&mut frame);
+        }
+        transition.show(&mut frame, &[bg_id.into()]);
+        frame.commit();
+    }
+}
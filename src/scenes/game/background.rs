@@ -1,6 +1,7 @@
-use super::shake::ScreenShake;
+use super::camera::{Camera, Layer};
 use agb::display::tiled::{
-    RegularBackground, RegularBackgroundSize, TileFormat, VRAM_MANAGER,
+    RegularBackground, RegularBackgroundId, RegularBackgroundSize, TileFormat,
+    VRAM_MANAGER,
 };
 use agb::display::{GraphicsFrame, Priority};
 use agb::include_background_gfx;
@@ -41,20 +42,22 @@ impl Background {
         }
     }
 
-    pub fn update(&mut self, screen_shake: &ScreenShake) {
-        if screen_shake.is_active() {
-            self.game_background
-                .set_scroll_pos(screen_shake.offset().round());
-        } else {
-            self.game_background.set_scroll_pos((0, 0));
-        }
+    pub fn update(&mut self, camera: &Camera) {
+        self.game_background
+            .set_scroll_pos(camera.offset(Layer::Playfield));
     }
 
-    pub fn show_game(&mut self, frame: &mut GraphicsFrame) {
-        self.game_background.show(frame);
+    pub fn show_game(
+        &mut self,
+        frame: &mut GraphicsFrame,
+    ) -> RegularBackgroundId {
+        self.game_background.show(frame)
     }
 
-    pub fn show_white(&mut self, frame: &mut GraphicsFrame) {
-        self.white_background.show(frame);
+    pub fn show_white(
+        &mut self,
+        frame: &mut GraphicsFrame,
+    ) -> RegularBackgroundId {
+        self.white_background.show(frame)
     }
 }
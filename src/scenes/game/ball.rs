@@ -1,4 +1,5 @@
 use crate::Fixed;
+use crate::save::BallKind;
 use crate::{Coordinates, Force};
 use agb::display::object::{AffineMatrixObject, AffineMode, ObjectAffine};
 use agb::display::{AffineMatrix, GraphicsFrame};
@@ -7,54 +8,83 @@ use agb::{
     include_aseprite,
 };
 
+use super::camera::{Camera, Layer};
 use super::config::GameConfig;
+use super::trail::Trail;
 
 include_aseprite!(
     mod sprites,
     "assets/ball.aseprite"
 );
 
+// Fails the build when `ball.aseprite` lacks a frame for some kind.
+const _: () = {
+    let mut i = 0;
+    while i < BallKind::ALL.len() {
+        let _ = sprites::BALL.sprite(BallKind::ALL[i].sprite());
+        i += 1;
+    }
+};
+
 pub struct Ball {
     pub position: Coordinates,
     pub velocity: Force,
-    sprite: ObjectAffine,
+    kind: BallKind,
     sprite_rotation: Fixed,
+    trail: Option<Trail>,
 }
 
 impl Ball {
     pub fn new(position: Coordinates) -> Self {
-        let affine_matrix: AffineMatrix = AffineMatrix::from_rotation(num!(0));
-        let affine_matrix_instance = AffineMatrixObject::new(affine_matrix);
         Self {
             position,
             velocity: vec2(num!(0), num!(0)),
-            sprite: ObjectAffine::new(
-                sprites::BALL.sprite(0),
-                affine_matrix_instance,
-                AffineMode::Affine,
-            ),
+            kind: BallKind::Identity,
             sprite_rotation: num!(0),
+            trail: None,
         }
     }
 
     pub fn reset_sprite(&mut self) {
         self.sprite_rotation = num!(0);
+        self.trail = None;
+    }
+
+    pub fn set_kind(&mut self, kind: BallKind) {
+        self.kind = kind;
+    }
+
+    /// Starts a fresh trail behind the ball, if trails are on.
+    pub fn start_trail(&mut self) {
+        if GameConfig::BALL_TRAIL {
+            self.trail = Some(Trail::new(self.kind));
+        }
     }
 
     pub fn update(&mut self) {
         self.sprite_rotation += num!(GameConfig::BALL_ROTATION_SPEED);
+        if let Some(trail) = &mut self.trail {
+            trail.record(self.position);
+        }
     }
 
-    pub fn show(self: &mut Ball, frame: &mut GraphicsFrame) {
+    pub fn show(self: &mut Ball, frame: &mut GraphicsFrame, camera: &Camera) {
         let affine_matrix: AffineMatrix =
             AffineMatrix::from_rotation(self.sprite_rotation);
 
         let affine_matrix_instance = AffineMatrixObject::new(affine_matrix);
 
-        self.sprite
-            .set_affine_matrix(affine_matrix_instance)
-            .set_pos(self.position.round())
-            .set_priority(agb::display::Priority::P2)
-            .show(frame);
+        ObjectAffine::new(
+            sprites::BALL.sprite(self.kind.sprite()),
+            affine_matrix_instance,
+            AffineMode::Affine,
+        )
+        .set_pos(camera.place(Layer::Playfield, self.position.round()))
+        .set_priority(agb::display::Priority::P2)
+        .show(frame);
+
+        if let Some(trail) = &self.trail {
+            trail.show(frame, camera);
+        }
     }
 }
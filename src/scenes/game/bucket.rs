@@ -6,6 +6,7 @@ use agb::{
     include_aseprite,
 };
 
+use super::camera::{Camera, Layer};
 use super::config::GameConfig;
 
 include_aseprite!(
@@ -22,13 +23,13 @@ pub struct Bucket {
 }
 
 impl Bucket {
-    pub fn new(position: Coordinates) -> Self {
+    pub fn new(position: Coordinates, speed_percent: i32) -> Self {
         let walls = Self::calculate_walls(position);
         Self {
             position,
             sprite: Object::new(sprites::BUCKET.sprite(0)),
             direction: num!(1.0),
-            speed: num!(GameConfig::BUCKET_SPEED),
+            speed: num!(GameConfig::BUCKET_SPEED) * speed_percent / 100,
             walls,
         }
     }
@@ -67,8 +68,10 @@ impl Bucket {
         self.walls = Self::calculate_walls(self.position);
     }
 
-    pub fn show(&mut self, frame: &mut GraphicsFrame) {
-        self.sprite.set_pos(self.position.round()).show(frame);
+    pub fn show(&mut self, frame: &mut GraphicsFrame, camera: &Camera) {
+        self.sprite
+            .set_pos(camera.place(Layer::Playfield, self.position.round()))
+            .show(frame);
     }
 
     pub fn is_in_bucket(&self, position: Coordinates) -> bool {
@@ -0,0 +1,76 @@
+use super::shake::ScreenShake;
+use crate::Force;
+use agb::fixnum::{Vector2D, vec2};
+use agb::rng::RandomNumberGenerator;
+
+/// What a game object is drawn as part of.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Layer {
+    /// Pegs, ball, bucket and what pops out of them.
+    Playfield,
+    /// Counters, gauges and the bag.
+    Hud,
+}
+
+impl Layer {
+    const COUNT: usize = 2;
+}
+
+/// The offset every game object goes through when shown, so that shakes
+/// move the sprites together with the background.
+pub struct Camera {
+    shake: ScreenShake,
+    still: [bool; Layer::COUNT],
+}
+
+impl Camera {
+    /// The HUD stays still by default.
+    pub fn new() -> Self {
+        let mut camera = Self {
+            shake: ScreenShake::inactive(),
+            still: [false; Layer::COUNT],
+        };
+        camera.set_still(Layer::Hud, true);
+        camera
+    }
+
+    /// Opts `layer` out of shakes, or back in.
+    pub fn set_still(&mut self, layer: Layer, still: bool) {
+        self.still[layer as usize] = still;
+    }
+
+    pub fn update(&mut self, rng: &mut RandomNumberGenerator) {
+        self.shake.update(rng);
+    }
+
+    pub fn shake(&mut self, duration: u32, intensity: i32) {
+        self.shake.start(duration, intensity);
+    }
+
+    pub fn shake_along(
+        &mut self,
+        duration: u32,
+        intensity: i32,
+        direction: Force,
+    ) {
+        self.shake.start_along(duration, intensity, direction);
+    }
+
+    /// How far `layer` is moved this frame, as a scroll position.
+    pub fn offset(&self, layer: Layer) -> Vector2D<i32> {
+        if self.still[layer as usize] {
+            vec2(0, 0)
+        } else {
+            self.shake.offset().round()
+        }
+    }
+
+    /// Where something at `position` on `layer` shows up on screen.
+    pub fn place(
+        &self,
+        layer: Layer,
+        position: Vector2D<i32>,
+    ) -> Vector2D<i32> {
+        position - self.offset(layer)
+    }
+}
@@ -6,6 +6,7 @@ pub struct GameConfig;
 impl GameConfig {
     // Core Game Settings
     pub const DELTA_TIME: f32 = 1.0 / 60.0;
+    pub const MAX_PEGS: usize = 50;
 
     // Screen Boundaries
     pub const SCREEN_BOTTOM: f32 = 168.0;
@@ -32,6 +33,9 @@ impl GameConfig {
     pub const BALL_RADIUS: i32 = 4;
     pub const PEG_RADIUS: i32 = 3;
     pub const BALL_ROTATION_SPEED: f32 = 0.005;
+    pub const BALL_TRAIL: bool = true;
+    pub const BALL_TRAIL_LENGTH: usize = 6;
+    pub const BALL_TRAIL_SPACING: u32 = 2;
 
     // Peg Force Radii
     pub const PEG_FORCE_RADII: [f32; 4] = [5.0, 10.0, 15.0, 20.0];
@@ -60,17 +64,31 @@ impl GameConfig {
     pub const JAUGE_SPRITE_WIDTH: i32 = 32;
     pub const COUNTER_DIGIT_SPACING: f32 = 4.0;
     pub const COUNTER_POSITION_OFFSET: f32 = 2.0;
+    pub const COUNTER_MAX_WIDTH: usize = 5;
     pub const INVENTORY_ITEM_SPACING: i32 = 12;
+    pub const TEXT_BOX_WIDTH: i32 = 46;
+    pub const TEXT_BOX_HEIGHT: i32 = 95;
+    pub const TEXT_PAGE_FRAMES: u32 = 120;
+    pub const RELIC_ROW_X: f32 = 28.0;
+    pub const RELIC_ROW_Y: f32 = 149.0;
+    pub const RELIC_SPACING: i32 = 12;
 
     // Visual Effects
     pub const SHAKE_DURATION: u32 = 10;
     pub const SHAKE_INTENSITY: i32 = 3;
     pub const FLASH_DURATION: u32 = 3;
+    pub const PEG_CLEAR_DELAY: u32 = 4;
+    pub const PARTICLE_POOL_SIZE: usize = 24;
+    pub const PARTICLE_SPAWN_BUDGET: usize = 12;
 
     pub fn ball_start_pos() -> Coordinates {
         vec2(num!(Self::BALL_START_X), num!(Self::BALL_START_Y))
     }
 
+    pub fn relic_row_pos() -> Coordinates {
+        vec2(num!(Self::RELIC_ROW_X), num!(Self::RELIC_ROW_Y))
+    }
+
     pub fn bucket_start_pos() -> Coordinates {
         vec2(num!(Self::BUCKET_START_X), num!(Self::BUCKET_START_Y))
     }
@@ -1,9 +1,12 @@
 use crate::Coordinates;
+use crate::number::{self, Number, Sign};
 use agb::display::GraphicsFrame;
 use agb::display::font::AlignmentKind;
-use agb::{fixnum::vec2, include_aseprite};
-use alloc::vec::Vec;
+use agb::display::object::{Object, Tag};
+use agb::fixnum::{num, vec2};
+use agb::include_aseprite;
 
+use super::camera::{Camera, Layer};
 use super::config::GameConfig;
 
 include_aseprite!(
@@ -11,89 +14,81 @@ include_aseprite!(
     "assets/numbers.aseprite"
 );
 
+/// Sprite set a counter draws with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Colour {
+    White,
+    Red,
+    Yellow,
+}
+
+impl Colour {
+    fn tag(self) -> &'static Tag {
+        match self {
+            Colour::White => &sprites::NUMBERS,
+            Colour::Red => &sprites::NUMBERSRED,
+            Colour::Yellow => &sprites::NUMBERSYELLOW,
+        }
+    }
+}
+
 pub struct Counter {
-    counter: i32,
+    counter: Number,
     position: Coordinates,
     alignment: AlignmentKind,
+    colour: Colour,
 }
 
 impl Counter {
     pub fn new(position: Coordinates, alignment: AlignmentKind) -> Self {
         Self {
-            counter: 0,
+            counter: Number::ZERO,
             position,
             alignment,
+            colour: Colour::White,
         }
     }
 
-    pub fn set(&mut self, value: i32) {
+    pub fn set(&mut self, value: Number) {
         self.counter = value;
     }
 
+    pub fn set_colour(&mut self, colour: Colour) {
+        self.colour = colour;
+    }
+
     pub fn reset(&mut self) {
-        self.counter = 0;
+        self.counter = Number::ZERO;
     }
 
-    pub fn show(&mut self, frame: &mut GraphicsFrame) {
-        let abs_counter = self.counter.abs();
+    /// Negative values are always drawn in red.
+    pub fn show(&mut self, frame: &mut GraphicsFrame, camera: &Camera) {
+        let colour = if self.counter < Number::ZERO {
+            Colour::Red
+        } else {
+            self.colour
+        };
+        let glyphs = number::glyphs(
+            self.counter,
+            GameConfig::COUNTER_MAX_WIDTH,
+            Sign::NegativeOnly,
+        );
 
-        if abs_counter == 0 {
-            let mut sprite =
-                agb::display::object::Object::new(sprites::NUMBERS.sprite(0));
-            match self.alignment {
-                AlignmentKind::Left => {
-                    sprite.set_pos(self.position.round()).show(frame)
-                }
-                AlignmentKind::Right => sprite
-                    .set_pos(
-                        self.position.round()
-                            - vec2(
-                                GameConfig::COUNTER_POSITION_OFFSET as i32,
-                                0,
-                            ),
-                    )
-                    .show(frame),
-                _ => {}
+        let spacing = num!(GameConfig::COUNTER_DIGIT_SPACING);
+        let start = match self.alignment {
+            AlignmentKind::Right => {
+                self.position.x
+                    - num!(GameConfig::COUNTER_POSITION_OFFSET)
+                    - spacing * (glyphs.len() as i32 - 1)
             }
-            return;
-        }
-
-        let mut digits = Vec::new();
-        let mut num = abs_counter;
-        while num > 0 {
-            digits.push((num % 10) as usize);
-            num /= 10;
-        }
-        digits.reverse();
+            _ => self.position.x,
+        };
 
-        for (i, &digit) in digits.iter().enumerate() {
-            let mut sprite = agb::display::object::Object::new(
-                sprites::NUMBERS.sprite(digit),
-            );
-            let digit_pos = match self.alignment {
-                AlignmentKind::Left => agb::fixnum::vec2(
-                    self.position.x
-                        + agb::fixnum::num!(GameConfig::COUNTER_DIGIT_SPACING)
-                            * i as i32,
-                    self.position.y,
-                ),
-                AlignmentKind::Right => agb::fixnum::vec2(
-                    self.position.x
-                        - agb::fixnum::num!(
-                            GameConfig::COUNTER_POSITION_OFFSET
-                        )
-                        - agb::fixnum::num!(GameConfig::COUNTER_DIGIT_SPACING)
-                            * (digits.len() - 1 - i) as i32,
-                    self.position.y,
-                ),
-                _ => agb::fixnum::vec2(
-                    self.position.x
-                        + agb::fixnum::num!(GameConfig::COUNTER_DIGIT_SPACING)
-                            * i as i32,
-                    self.position.y,
-                ),
-            };
-            sprite.set_pos(digit_pos.round()).show(frame);
+        for (i, glyph) in glyphs.iter().enumerate() {
+            let position = vec2(start + spacing * i as i32, self.position.y);
+            Object::new(colour.tag().sprite(glyph.frame()))
+                .set_pos(camera.place(Layer::Hud, position.round()))
+                .show(frame);
         }
     }
 }
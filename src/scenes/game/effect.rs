@@ -1,5 +1,7 @@
-use crate::save::BallKind;
+use crate::save::{BallKind, MAX_INVENTORY, OwnedBall};
 use crate::scenes::game::score::Score;
+use alloc::format;
+use alloc::string::String;
 use alloc::vec::Vec;
 use heapless::Vec as HeaplessVec;
 
@@ -8,6 +10,7 @@ use crate::peg::Kind;
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct BallData {
     kind: BallKind,
+    tier: u8,
     active: ActiveEffect,
     passive: PassiveEffect,
 }
@@ -17,6 +20,7 @@ impl BallData {
     pub fn empty() -> Self {
         Self {
             kind: BallKind::Identity,
+            tier: 1,
             active: ActiveEffect::Identity,
             passive: PassiveEffect::Identity,
         }
@@ -26,19 +30,58 @@ impl BallData {
         match kind {
             BallKind::Identity => Self {
                 kind,
+                tier: 1,
                 active: ActiveEffect::Identity,
                 passive: PassiveEffect::Identity,
             },
             BallKind::TheDoubler => Self {
                 kind,
+                tier: 1,
                 active: ActiveEffect::AddMult(1),
                 passive: PassiveEffect::Identity,
             },
             BallKind::SmallGrabber => Self {
                 kind,
+                tier: 1,
                 active: ActiveEffect::AddBase(10),
                 passive: PassiveEffect::Identity,
             },
+            BallKind::BigGrabber => Self {
+                kind,
+                tier: 1,
+                active: ActiveEffect::AddBase(30),
+                passive: PassiveEffect::Identity,
+            },
+            BallKind::RedGiant => Self {
+                kind,
+                tier: 1,
+                active: ActiveEffect::AddMult(2),
+                passive: PassiveEffect::AddMult(1),
+            },
+            BallKind::TheCrown => Self {
+                kind,
+                tier: 1,
+                active: ActiveEffect::Identity,
+                passive: PassiveEffect::AddBase(5),
+            },
+        }
+    }
+
+    /// Merged balls multiply their effect values by their tier.
+    pub fn new(ball: OwnedBall) -> Self {
+        let base = Self::from_kind(ball.kind);
+        Self {
+            tier: ball.tier,
+            active: base.active.scaled(ball.tier as i32),
+            passive: base.passive.scaled(ball.tier as i32),
+            ..base
+        }
+    }
+
+    pub fn owned(&self) -> OwnedBall {
+        OwnedBall {
+            kind: self.kind,
+            tier: self.tier,
         }
     }
 
@@ -53,25 +96,57 @@ impl BallData {
     pub fn kind(&self) -> BallKind {
         self.kind
     }
+
+    /// Name, one line per effect, then the flavour line, using text box
+    /// markup.
+    pub fn description(&self) -> String {
+        let tier = match self.tier {
+            0 | 1 => "",
+            2 => " II",
+            _ => " III",
+        };
+        let mut text = format!(
+            "{{{}}}{}{tier}{{white}}\n",
+            self.kind.rarity().colour(),
+            self.kind.name()
+        );
+        let effects = [self.active.description(), self.passive.description()];
+
+        if effects.iter().all(Option::is_none) {
+            text.push_str("No effect\n");
+        }
+        for effect in effects.into_iter().flatten() {
+            text.push_str(&effect);
+            text.push('\n');
+        }
+
+        text.push_str(self.kind.flavour());
+        text
+    }
 }
 
-pub fn from_kinds(kinds: &HeaplessVec<BallKind, 10>) -> Vec<BallData> {
-    kinds
-        .iter()
-        .map(|kind| BallData::from_kind(*kind))
-        .collect()
+pub fn from_balls(
+    balls: &HeaplessVec<OwnedBall, MAX_INVENTORY>,
+) -> Vec<BallData> {
+    balls.iter().map(|ball| BallData::new(*ball)).collect()
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PassiveEffect {
     Identity,
-    #[allow(dead_code)]
     AddMult(i32),
-    #[allow(dead_code)]
     AddBase(i32),
 }
 
 impl PassiveEffect {
+    fn scaled(self, factor: i32) -> Self {
+        match self {
+            PassiveEffect::Identity => self,
+            PassiveEffect::AddMult(m) => PassiveEffect::AddMult(m * factor),
+            PassiveEffect::AddBase(b) => PassiveEffect::AddBase(b * factor),
+        }
+    }
+
     pub fn apply(self, score: Score) -> Score {
         match self {
             PassiveEffect::Identity => score,
@@ -79,17 +154,36 @@ impl PassiveEffect {
             PassiveEffect::AddBase(b) => score.add(b, 0, 0),
         }
     }
+
+    pub fn description(self) -> Option<String> {
+        match self {
+            PassiveEffect::Identity => None,
+            PassiveEffect::AddMult(m) => {
+                Some(format!("{m:+} {{red}}mult{{white}} on every peg"))
+            }
+            PassiveEffect::AddBase(b) => {
+                Some(format!("{b:+} {{blue}}base{{white}} on every peg"))
+            }
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ActiveEffect {
     Identity,
     AddMult(i32),
-    #[allow(dead_code)]
     AddBase(i32),
 }
 
 impl ActiveEffect {
+    fn scaled(self, factor: i32) -> Self {
+        match self {
+            ActiveEffect::Identity => self,
+            ActiveEffect::AddMult(m) => ActiveEffect::AddMult(m * factor),
+            ActiveEffect::AddBase(b) => ActiveEffect::AddBase(b * factor),
+        }
+    }
+
     pub fn apply(self, score: Score, kind: Kind) -> Score {
         match (self, kind) {
             (ActiveEffect::Identity, _) => score,
@@ -98,13 +192,26 @@ impl ActiveEffect {
             (_, _) => score,
         }
     }
+
+    pub fn description(self) -> Option<String> {
+        match self {
+            ActiveEffect::Identity => None,
+            ActiveEffect::AddMult(m) => {
+                Some(format!("{m:+} {{red}}mult{{white}} on [red] pegs"))
+            }
+            ActiveEffect::AddBase(b) => {
+                Some(format!("{b:+} {{blue}}base{{white}} on [blue] pegs"))
+            }
+        }
+    }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum BucketEffect {
     Identity,
-    #[allow(dead_code)]
     MultiplyMult(i32),
+    AddBase(i32),
+    AddCoins(i32),
 }
 
 impl BucketEffect {
@@ -112,6 +219,35 @@ impl BucketEffect {
         match self {
             BucketEffect::Identity => score,
             BucketEffect::MultiplyMult(m) => score.mult(1, m, 1),
+            BucketEffect::AddBase(b) => score.add(b, 0, 0),
+            BucketEffect::AddCoins(c) => score.add(0, 0, c),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BucketEffect::Identity => "Plain Bucket",
+            BucketEffect::MultiplyMult(_) => "Deep Bucket",
+            BucketEffect::AddBase(_) => "Heavy Bucket",
+            BucketEffect::AddCoins(_) => "Golden Bucket",
         }
     }
+
+    /// Same layout as `BallData::description`.
+    pub fn description(self) -> String {
+        let effect = match self {
+            BucketEffect::Identity => String::from("No effect"),
+            BucketEffect::MultiplyMult(m) => {
+                format!("x{m} {{red}}mult{{white}} when bucketed")
+            }
+            BucketEffect::AddBase(b) => {
+                format!("{b:+} {{blue}}base{{white}} when bucketed")
+            }
+            BucketEffect::AddCoins(c) => {
+                format!("{c:+} [coin] when bucketed")
+            }
+        };
+
+        format!("{{green}}{}{{white}}\n{effect}", self.name())
+    }
 }
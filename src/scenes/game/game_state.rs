@@ -1,47 +1,68 @@
 use super::{
-    super::text_box::TextBox,
+    super::text_box::{Advance, TextBox},
     background::Background,
     ball::Ball,
+    camera::Camera,
     config::GameConfig,
-    counter::Counter,
+    counter::{Colour, Counter},
     effect::{BallData, BucketEffect},
     input::{InputHandler, InventoryNavigation, MovementDirection},
     inventory::InventoryPresenter,
     jauge::Jauge,
     launcher::Launcher,
+    particles::{Burst, Particles},
     peg::Pegs,
     peg_generator::PegGenerator,
     physics_handler::PhysicsHandler,
-    points_pres::PointsPres,
+    points_pres::{self, PointsPres},
+    relic::RelicPresenter,
     saved_pres::SavedPres,
-    score::ScoreManager,
-    shake::{ScreenShake, WhiteFlash},
+    score::{Score, ScoreManager},
+    scoring::{Event, Pipeline, Trace},
+    shake::WhiteFlash,
     state::{State, StateManager},
 };
 use crate::{
-    error::Error, level::Level, physics::Physics, save::Save,
+    error::Error,
+    level::{Level, LevelModifier},
+    number::Number,
+    physics::Physics,
+    save::{LevelResult, OwnedBall, RelicKind, Save},
     scenes::game::bucket::Bucket,
 };
 use agb::{
     InternalAllocator,
-    display::font::AlignmentKind,
+    display::{font::AlignmentKind, tiled::BackgroundId},
     fixnum::{num, vec2},
     input::ButtonController,
     rng::RandomNumberGenerator,
 };
-use alloc::{boxed::Box, vec, vec::Vec};
+use alloc::{boxed::Box, vec::Vec};
 use const_random::const_random;
 
 type InventoryIndex = usize;
 
+/// Completes the run seed and depth into a full peg physics seed.
+const PHYSICS_SEED: [u32; 2] = [0x9e37_79b9, 0x85eb_ca6b];
+
 pub struct GameState<const MAX_PEGS: usize> {
     // Game data
     inventory: Vec<BallData>,
     current_ball_data: Option<BallData>,
+    spent_balls: Vec<BallData>,
     bucket_effects: Vec<BucketEffect>,
+    relics: Vec<RelicKind>,
+    modifiers: Vec<LevelModifier>,
     selected_inventory_index: InventoryIndex,
+    selected_relic: Option<usize>,
+    /// Cosmetics only: particles and camera shake.
     rng: RandomNumberGenerator,
+    /// Only drives peg motion, so that it replays with the run seed.
+    physics_rng: RandomNumberGenerator,
+    peg_jitter: i32,
     peg_generators: Vec<PegGenerator>,
+    pegs_hit: u32,
+    clear_timer: u32,
 
     // Core systems
     physics: Box<Physics<MAX_PEGS>, InternalAllocator>,
@@ -59,6 +80,7 @@ pub struct GameState<const MAX_PEGS: usize> {
     mult_counter: Counter,
     coin_counter: Counter,
     inventory_presenter: InventoryPresenter,
+    relic_presenter: RelicPresenter,
     text_box: TextBox,
     jauge: Jauge<0, 50>,
     power_jauge: Jauge<0, 50>,
@@ -67,8 +89,9 @@ pub struct GameState<const MAX_PEGS: usize> {
 
     // Rendering and effects
     background: Background,
-    screen_shake: ScreenShake,
+    camera: Camera,
     white_flash: WhiteFlash,
+    particles: Particles,
 }
 
 impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
@@ -86,27 +109,79 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
             >(&mut rng, &level),
             InternalAllocator,
         );
-        let physics = Box::new_in(
+        let mut physics = Box::new_in(
             Physics::<MAX_PEGS>::new(&pegs.positions, &pegs.collidable)?,
             InternalAllocator,
         );
+        for (i, &kind) in pegs.kind.iter().enumerate() {
+            physics.set_motion(i, level.motion(kind), pegs.positions[i]);
+        }
+
+        let mut text_box = TextBox::new(
+            vec2(189, 5),
+            GameConfig::TEXT_BOX_WIDTH,
+            GameConfig::TEXT_BOX_HEIGHT,
+        );
+        text_box.set_advance(Advance::Timed(GameConfig::TEXT_PAGE_FRAMES));
+
+        let inventory = super::effect::from_balls(save.inventory());
+        let bucket_effects: Vec<BucketEffect> =
+            core::iter::once(BucketEffect::Identity)
+                .chain(save.bucket_effects().iter().copied())
+                .collect();
+        let relics: Vec<RelicKind> = save.relics().iter().copied().collect();
+        let modifiers: Vec<LevelModifier> = level.modifiers().to_vec();
+
+        let mut trace = Trace::new();
+        let coins = Pipeline {
+            inventory: &inventory,
+            current_ball: None,
+            bucket_effects: &bucket_effects,
+            relics: &relics,
+            modifiers: &modifiers,
+        }
+        .run(
+            Event::LevelStart,
+            Score::new(0, 0, save.coins()),
+            &mut trace,
+        )
+        .coins
+        .get();
+        trace.flush();
+
+        let mut coin_counter =
+            Counter::new(vec2(num!(234), num!(145)), AlignmentKind::Right);
+        coin_counter.set_colour(Colour::Yellow);
 
         Ok(Self {
-            inventory: super::effect::from_kinds(save.inventory()),
+            inventory,
             current_ball_data: None,
-            bucket_effects: vec![BucketEffect::Identity],
+            spent_balls: Vec::new(),
+            bucket_effects,
+            relics,
+            modifiers,
             selected_inventory_index: 0,
+            selected_relic: None,
             rng,
+            physics_rng: RandomNumberGenerator::new_with_seed([
+                save.seed().max(1),
+                save.level().max(1) as u32,
+                PHYSICS_SEED[0],
+                PHYSICS_SEED[1],
+            ]),
+            peg_jitter: level.peg_jitter(),
             peg_generators: Vec::new(),
+            pegs_hit: 0,
+            clear_timer: 0,
             physics,
             pegs,
-            score_manager: ScoreManager::new(
-                level.target_score(),
-                save.coins(),
-            ),
+            score_manager: ScoreManager::new(level.target_score(), coins),
             state_manager: StateManager::new(),
             ball: Ball::new(GameConfig::ball_start_pos()),
-            bucket: Bucket::new(GameConfig::bucket_start_pos()),
+            bucket: Bucket::new(
+                GameConfig::bucket_start_pos(),
+                level.bucket_speed_percent(),
+            ),
             launcher: Launcher::new(GameConfig::ball_start_pos()),
             base_counter: Counter::new(
                 vec2(num!(217), num!(125)),
@@ -116,31 +191,58 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
                 vec2(num!(206), num!(125)),
                 AlignmentKind::Right,
             ),
-            coin_counter: Counter::new(
-                vec2(num!(234), num!(145)),
-                AlignmentKind::Right,
-            ),
+            coin_counter,
             inventory_presenter: InventoryPresenter::new(vec2(
                 num!(8),
                 num!(16),
             )),
-            text_box: TextBox::new(vec2(189, 5), 46),
+            relic_presenter: RelicPresenter::new(GameConfig::relic_row_pos()),
+            text_box,
             jauge: Jauge::new(vec2(num!(184), num!(104))),
             point_pres: Vec::new(),
             saved_pres: None,
             power_jauge: Jauge::new(GameConfig::power_gauge_pos()),
             background: Background::new(),
-            screen_shake: ScreenShake::inactive(),
+            camera: Camera::new(),
             white_flash: WhiteFlash::new(),
+            particles: Particles::new(),
         })
     }
 
     pub fn pop_ball(&mut self) -> Result<(), Error> {
-        if let Some(ball_data) = self.inventory.pop() {
-            self.current_ball_data = Some(ball_data);
-            Ok(())
-        } else {
-            Err(Error::NoBalls)
+        if self.inventory.is_empty() {
+            return Err(Error::NoBalls);
+        }
+
+        self.current_ball_data = Some(self.inventory.remove(0));
+        Ok(())
+    }
+
+    /// The loaded ball first, then the queue, then the balls lost during
+    /// this level.
+    pub fn ball_order(&self) -> impl Iterator<Item = OwnedBall> + '_ {
+        self.current_ball_data
+            .iter()
+            .chain(self.inventory.iter())
+            .chain(self.spent_balls.iter())
+            .map(BallData::owned)
+    }
+
+    pub fn coins(&self) -> i32 {
+        self.score_manager.coins()
+    }
+
+    pub fn relics(&self) -> &[RelicKind] {
+        &self.relics
+    }
+
+    pub fn level_result(&self, save: &Save) -> LevelResult {
+        LevelResult {
+            damage: self.score_manager.damages(),
+            target: self.score_manager.target_score(),
+            coins: self.coins() - save.coins(),
+            best_shot: self.score_manager.best_shot(),
+            pegs_hit: self.pegs_hit,
         }
     }
 
@@ -154,9 +256,7 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
 
     pub fn set_text_to_current_ball(&mut self) {
         match self.current_ball_data {
-            Some(ball_data) => {
-                self.text_box.set_text(ball_data.kind().description())
-            }
+            Some(ball_data) => self.text_box.set_text(&ball_data.description()),
             None => self.text_box.remove(),
         }
     }
@@ -166,8 +266,10 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
         input: &ButtonController,
     ) -> Result<State, Error> {
         self.ball.reset_sprite();
-        PhysicsHandler::update_pegs(&mut self.physics, &mut self.pegs)?;
-        self.update_peg_generation()?;
+        if let Some(ball_data) = self.current_ball_data {
+            self.ball.set_kind(ball_data.kind());
+        }
+        self.update_pegs()?;
         self.bucket
             .update::<{ GameConfig::WALL_LEFT + 2 }, { GameConfig::WALL_RIGHT - 2 }>();
         self.update_pres();
@@ -199,14 +301,12 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
                 self.launcher.velocity() * power,
                 num!(GameConfig::BALL_START_Y) * power,
             );
+            self.ball.start_trail();
             return Ok(State::Falling);
         }
 
         if InputHandler::is_inventory_pressed(input) {
-            if let Some(ball_data) = self.inventory.first() {
-                self.text_box.set_text(ball_data.kind().description());
-            }
-            return Ok(State::InInventory);
+            return Ok(self.open_inventory());
         }
 
         Ok(State::Aiming)
@@ -222,39 +322,136 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
             return self.state_manager.return_to_previous();
         }
 
-        match InputHandler::get_inventory_navigation(input) {
-            InventoryNavigation::Up if self.selected_inventory_index > 0 => {
-                self.selected_inventory_index -= 1;
-                self.text_box.set_text(
-                    self.inventory[self.selected_inventory_index]
-                        .kind()
-                        .description(),
-                );
+        if InputHandler::is_text_advance_pressed(input) {
+            self.text_box.next_page();
+        }
+
+        if InputHandler::is_select_pressed(input)
+            && self.selected_relic.is_none()
+        {
+            self.load_selected_ball()?;
+        }
+
+        let navigation = InputHandler::get_inventory_navigation(input);
+        let reorder = InputHandler::is_reorder_held(input);
+        match navigation {
+            InventoryNavigation::Left | InventoryNavigation::Right => {
+                self.navigate_relics(navigation, reorder)
             }
-            InventoryNavigation::Down
-                if self.selected_inventory_index < self.inventory.len() - 1 =>
+            InventoryNavigation::Up | InventoryNavigation::Down
+                if self.selected_relic.take().is_some() =>
             {
-                self.selected_inventory_index += 1;
-                self.text_box.set_text(
-                    self.inventory[self.selected_inventory_index]
-                        .kind()
-                        .description(),
-                );
+                self.set_text_to_selected_ball()
             }
-            _ => {}
+            _ => self.navigate_balls(navigation, reorder),
         }
 
         self.inventory_presenter.update();
         Ok(State::InInventory)
     }
 
+    fn navigate_balls(
+        &mut self,
+        navigation: InventoryNavigation,
+        reorder: bool,
+    ) {
+        let selected = self.selected_inventory_index;
+        let target = match navigation {
+            InventoryNavigation::Up => selected.checked_sub(1),
+            InventoryNavigation::Down => {
+                Some(selected + 1).filter(|&i| i < self.inventory.len())
+            }
+            _ => None,
+        };
+
+        if let Some(target) = target {
+            if reorder {
+                self.inventory.swap(selected, target);
+            }
+            self.selected_inventory_index = target;
+            self.set_text_to_selected_ball();
+        }
+    }
+
+    /// Left and Right move to the relic row, where order matters as relics
+    /// are evaluated from left to right.
+    fn navigate_relics(
+        &mut self,
+        navigation: InventoryNavigation,
+        reorder: bool,
+    ) {
+        if self.relics.is_empty() {
+            return;
+        }
+
+        let target = match (self.selected_relic, navigation) {
+            (None, _) => Some(0),
+            (Some(selected), InventoryNavigation::Left) => {
+                selected.checked_sub(1)
+            }
+            (Some(selected), _) => {
+                Some(selected + 1).filter(|&i| i < self.relics.len())
+            }
+        };
+
+        if let Some(target) = target {
+            if reorder && let Some(selected) = self.selected_relic {
+                self.relics.swap(selected, target);
+            }
+            self.selected_relic = Some(target);
+            self.text_box.set_text(&self.relics[target].description());
+        }
+    }
+
+    fn open_inventory(&mut self) -> State {
+        self.selected_relic = None;
+        self.selected_inventory_index = self
+            .selected_inventory_index
+            .min(self.inventory.len().saturating_sub(1));
+        self.set_text_to_selected_ball();
+        State::InInventory
+    }
+
+    fn set_text_to_selected_ball(&mut self) {
+        if let Some(ball_data) =
+            self.inventory.get(self.selected_inventory_index)
+        {
+            self.text_box.set_text(&ball_data.description());
+        }
+    }
+
+    /// While aiming, the selected ball is swapped with the loaded one. Once
+    /// the loaded ball is in flight, the selected ball is queued next
+    /// instead.
+    fn load_selected_ball(&mut self) -> Result<(), Error> {
+        let selected = self.selected_inventory_index;
+        if selected >= self.inventory.len() {
+            return Ok(());
+        }
+
+        match self.state_manager.return_to_previous()? {
+            State::Aiming => {
+                if let Some(current) = &mut self.current_ball_data {
+                    core::mem::swap(current, &mut self.inventory[selected]);
+                }
+            }
+            _ => {
+                let ball_data = self.inventory.remove(selected);
+                self.inventory.insert(0, ball_data);
+                self.selected_inventory_index = 0;
+            }
+        }
+
+        self.set_text_to_selected_ball();
+        Ok(())
+    }
+
     pub fn update_falling(
         &mut self,
         input: &ButtonController,
     ) -> Result<State, Error> {
         self.ball.update();
-        PhysicsHandler::update_pegs(&mut self.physics, &mut self.pegs)?;
-        self.update_peg_generation()?;
+        self.update_pegs()?;
         self.bucket
             .update::<{ GameConfig::WALL_LEFT + 2 }, { GameConfig::WALL_RIGHT - 2 }>();
         self.update_pres();
@@ -268,14 +465,22 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
                 &self.bucket.walls,
             )?;
 
+        let bounced = PhysicsHandler::bounced_off_side_wall(
+            position,
+            self.ball.velocity,
+            velocity,
+        );
         self.ball.position = position;
         self.ball.velocity = velocity;
 
         self.process_peg_collisions(&touched)?;
+        if bounced {
+            self.process_hit(Event::WallBounce);
+        }
 
         if self.ball.position.y > num!(GameConfig::SCREEN_BOTTOM) {
             self.start_end_effects();
-            return Ok(State::Counting { bucketed: None });
+            return Ok(State::Clearing { bucketed: None });
         }
 
         if self.bucket.is_in_bucket(self.ball.position) {
@@ -283,46 +488,74 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
             self.saved_pres = Some(SavedPres::new(
                 self.ball.position + vec2(num!(0), num!(-10)),
             ));
-            return Ok(State::Counting {
+            return Ok(State::Clearing {
                 bucketed: self.current_ball_data,
             });
         }
 
         if InputHandler::is_inventory_pressed(input) {
-            return Ok(State::InInventory);
+            return Ok(self.open_inventory());
         }
 
         Ok(State::Falling)
     }
 
+    /// Clears a lit peg every few frames, in the order they were hit.
+    pub fn update_clearing(
+        &mut self,
+        bucketed: Option<BallData>,
+    ) -> Result<State, Error> {
+        self.ball.position = GameConfig::ball_start_pos();
+        self.ball.reset_sprite();
+        self.update_pegs()?;
+        self.bucket
+            .update::<{ GameConfig::WALL_LEFT + 2 }, { GameConfig::WALL_RIGHT - 2 }>();
+        self.update_pres();
+
+        if self.clear_timer > 0 {
+            self.clear_timer -= 1;
+            return Ok(State::Clearing { bucketed });
+        }
+
+        match self.pegs.clear_next() {
+            Some(i) => {
+                self.particles.burst(
+                    Burst::PegHit(self.pegs.kind[i]),
+                    self.pegs.positions[i],
+                    &mut self.rng,
+                );
+                self.clear_timer = GameConfig::PEG_CLEAR_DELAY;
+                Ok(State::Clearing { bucketed })
+            }
+            None => Ok(State::Counting { bucketed }),
+        }
+    }
+
     pub fn update_counting(
         &mut self,
         bucketed_ball: Option<BallData>,
     ) -> Result<State, Error> {
         self.ball.position = GameConfig::ball_start_pos();
-        PhysicsHandler::update_pegs(&mut self.physics, &mut self.pegs)?;
-        self.update_peg_generation()?;
+        self.update_pegs()?;
         self.bucket
             .update::<{ GameConfig::WALL_LEFT + 2 }, { GameConfig::WALL_RIGHT - 2 }>();
         self.update_pres();
 
-        PhysicsHandler::hide_non_collidable_pegs(&mut self.pegs);
+        let truncated = self.physics.take_truncated_queries();
+        if cfg!(feature = "grid-trace") && truncated > 0 {
+            agb::println!("Crowded peg grid: {} neighbours skipped", truncated);
+        }
 
         if bucketed_ball.is_some() {
-            self.score_manager.process_bucket_bonus(
-                &self.bucket_effects,
-                &mut self.mult_counter,
-                &mut self.base_counter,
-                &mut self.coin_counter,
-            );
+            self.process_shot_event(Event::Bucket);
         }
-
+        self.process_shot_event(Event::ShotEnd);
         let (damages, _) = self.score_manager.extract_final_score();
         self.score_manager
             .reset_counters(&mut self.mult_counter, &mut self.base_counter);
 
         self.jauge.set(
-            self.score_manager.target_score() - damages,
+            Number::from(self.score_manager.target_score()) - damages,
             0,
             self.score_manager.target_score(),
         );
@@ -332,17 +565,18 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
 
     pub fn update_effects(&mut self) {
         self.text_box.update();
-        self.screen_shake.update(&mut self.rng);
+        self.camera.update(&mut self.rng);
         self.white_flash.update();
     }
 
     pub fn update_pres(&mut self) {
         self.point_pres.retain_mut(|pp| pp.update());
+        self.particles.update();
 
-        if let Some(sp) = &mut self.saved_pres {
-            if !sp.update() {
-                self.saved_pres = None;
-            }
+        if let Some(sp) = &mut self.saved_pres
+            && !sp.update()
+        {
+            self.saved_pres = None;
         }
     }
 
@@ -350,49 +584,115 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
         self.state_manager.transition_to(new_state);
     }
 
-    pub fn add_bucketed_ball(&mut self, ball_data: BallData) {
-        self.inventory.push(ball_data);
+    /// Bucketed balls go back to the end of the queue, the others are
+    /// spent for the rest of the level.
+    pub fn end_shot(&mut self, bucketed: Option<BallData>) {
+        match bucketed {
+            Some(ball_data) => self.inventory.push(ball_data),
+            None => self.spent_balls.extend(self.current_ball_data),
+        }
+        self.current_ball_data = None;
     }
 
-    pub fn show(&mut self, frame: &mut agb::display::GraphicsFrame) {
-        self.background.update(&self.screen_shake);
+    pub fn show(
+        &mut self,
+        frame: &mut agb::display::GraphicsFrame,
+    ) -> BackgroundId {
+        self.background.update(&self.camera);
 
         if self.white_flash.is_active() {
-            self.background.show_white(frame);
+            self.background.show_white(frame).into()
         } else {
-            self.render_game_objects(frame);
+            self.render_game_objects(frame)
         }
     }
 
-    fn render_game_objects(&mut self, frame: &mut agb::display::GraphicsFrame) {
-        self.pegs.show(frame);
-        self.ball.show(frame);
-        self.bucket.show(frame);
-        self.background.show_game(frame);
-        self.base_counter.show(frame);
-        self.mult_counter.show(frame);
-        self.coin_counter.show(frame);
+    fn render_game_objects(
+        &mut self,
+        frame: &mut agb::display::GraphicsFrame,
+    ) -> BackgroundId {
+        let camera = &self.camera;
+        self.pegs.show(frame, camera);
+        self.ball.show(frame, camera);
+        self.bucket.show(frame, camera);
+        let bg_id = self.background.show_game(frame);
+        frame
+            .blend()
+            .object_transparency(num!(0.5), num!(0.5))
+            .enable_background(bg_id);
+        self.base_counter.show(frame, camera);
+        self.mult_counter.show(frame, camera);
+        self.coin_counter.show(frame, camera);
         self.inventory_presenter.show(
             frame,
+            camera,
+            self.current_ball_data.as_ref(),
             &self.inventory,
             self.selected_inventory_index,
-            matches!(self.state_manager.current(), State::InInventory),
+            matches!(self.state_manager.current(), State::InInventory)
+                && self.selected_relic.is_none(),
+        );
+        self.relic_presenter.show(
+            frame,
+            camera,
+            &self.relics,
+            self.selected_relic
+                .filter(|_| self.state_manager.current() == State::InInventory),
         );
         self.text_box.show(frame);
-        self.jauge.show(frame);
+        self.jauge.show(frame, camera);
 
         for pp in self.point_pres.iter_mut() {
-            pp.show(frame);
+            pp.show(frame, camera);
         }
 
         if let Some(sp) = &mut self.saved_pres {
-            sp.show(frame);
+            sp.show(frame, camera);
         }
 
         if matches!(self.state_manager.current(), State::Aiming) {
-            self.launcher.show(frame);
-            self.power_jauge.show(frame);
+            self.launcher.show(frame, camera);
+            self.power_jauge.show(frame, camera);
         }
+
+        // Last, so that running out of object slots only costs particles.
+        self.particles.show(frame, camera);
+
+        bg_id.into()
+    }
+
+    fn process_hit(&mut self, event: Event) -> Score {
+        let pipeline = Pipeline {
+            inventory: &self.inventory,
+            current_ball: self.current_ball_data.as_ref(),
+            bucket_effects: &self.bucket_effects,
+            relics: &self.relics,
+            modifiers: &self.modifiers,
+        };
+        self.score_manager.process_hit(
+            event,
+            &pipeline,
+            &mut self.mult_counter,
+            &mut self.base_counter,
+            &mut self.coin_counter,
+        )
+    }
+
+    fn process_shot_event(&mut self, event: Event) {
+        let pipeline = Pipeline {
+            inventory: &self.inventory,
+            current_ball: self.current_ball_data.as_ref(),
+            bucket_effects: &self.bucket_effects,
+            relics: &self.relics,
+            modifiers: &self.modifiers,
+        };
+        self.score_manager.process_shot_event(
+            event,
+            &pipeline,
+            &mut self.mult_counter,
+            &mut self.base_counter,
+            &mut self.coin_counter,
+        );
     }
 
     fn process_peg_collisions(
@@ -400,24 +700,21 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
         touched: &[usize],
     ) -> Result<(), Error> {
         let mut touched_green_pegs: Vec<usize> = Vec::new();
+        self.pegs_hit += touched.len() as u32;
 
         for &t in touched {
             let peg_kind = self.pegs.kind[t];
-            self.pegs.collidable[t] = false;
-            self.pegs.showable[t] = false;
-
-            let score = self.score_manager.process_peg_hit(
-                self.pegs.kind[t],
-                &self.inventory,
-                &self.current_ball_data,
-                &mut self.mult_counter,
-                &mut self.base_counter,
-                &mut self.coin_counter,
-            );
+            self.pegs.light(t);
+
+            let score = self.process_hit(Event::PegHit(peg_kind));
 
-            if let Some(pp) =
+            if let Some(mut pp) =
                 PointsPres::new(self.pegs.positions[t], score, peg_kind)
             {
+                // Dark digits get lost on the blue background.
+                if peg_kind == crate::peg::Kind::Blue {
+                    pp.set_colour(points_pres::Colour::White);
+                }
                 self.point_pres.push(pp);
             }
 
@@ -426,15 +723,19 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
             }
         }
 
-        if !touched.is_empty() {
-            self.screen_shake.start(
+        // The playfield recoils along the normal of the first impact.
+        if let Some(&first) = touched.first() {
+            self.camera.shake_along(
                 GameConfig::SHAKE_DURATION,
                 GameConfig::SHAKE_INTENSITY - 2,
+                self.ball.position - self.pegs.positions[first],
             );
         }
 
         for t in touched_green_pegs {
             let peg_position = self.pegs.positions[t];
+            self.particles
+                .burst(Burst::Spores, peg_position, &mut self.rng);
             self.peg_generators
                 .push(PegGenerator::new(10, 10, peg_position));
         }
@@ -442,6 +743,16 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
         Ok(())
     }
 
+    fn update_pegs(&mut self) -> Result<(), Error> {
+        PhysicsHandler::update_pegs(
+            &mut self.physics,
+            &mut self.pegs,
+            &mut self.physics_rng,
+            self.peg_jitter,
+        )?;
+        self.update_peg_generation()
+    }
+
     fn update_peg_generation(&mut self) -> Result<(), Error> {
         for generator in &mut self.peg_generators {
             if generator.update() {
@@ -449,7 +760,7 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
                     &mut self.pegs,
                     &mut self.physics,
                     generator.position(),
-                    &mut self.rng,
+                    &mut self.physics_rng,
                 )?;
             }
         }
@@ -460,8 +771,8 @@ impl<const MAX_PEGS: usize> GameState<MAX_PEGS> {
     }
 
     fn start_end_effects(&mut self) {
-        self.screen_shake
-            .start(GameConfig::SHAKE_DURATION, GameConfig::SHAKE_INTENSITY);
+        self.camera
+            .shake(GameConfig::SHAKE_DURATION, GameConfig::SHAKE_INTENSITY);
         self.white_flash.start(GameConfig::FLASH_DURATION);
     }
 }
@@ -28,6 +28,18 @@ impl InputHandler {
         input.is_just_released(Button::A)
     }
 
+    pub fn is_text_advance_pressed(input: &ButtonController) -> bool {
+        input.is_just_pressed(Button::R)
+    }
+
+    pub fn is_select_pressed(input: &ButtonController) -> bool {
+        input.is_just_pressed(Button::A)
+    }
+
+    pub fn is_reorder_held(input: &ButtonController) -> bool {
+        input.is_pressed(Button::B)
+    }
+
     pub fn is_inventory_pressed(input: &ButtonController) -> bool {
         input.is_just_pressed(Button::SELECT)
     }
@@ -39,6 +51,10 @@ impl InputHandler {
             InventoryNavigation::Up
         } else if input.is_just_pressed(Button::DOWN) {
             InventoryNavigation::Down
+        } else if input.is_just_pressed(Button::LEFT) {
+            InventoryNavigation::Left
+        } else if input.is_just_pressed(Button::RIGHT) {
+            InventoryNavigation::Right
         } else {
             InventoryNavigation::None
         }
@@ -56,5 +72,7 @@ pub enum MovementDirection {
 pub enum InventoryNavigation {
     Up,
     Down,
+    Left,
+    Right,
     None,
 }
@@ -4,10 +4,11 @@ use crate::scenes::game::effect::BallData;
 use agb::display::GraphicsFrame;
 use agb::display::object::Object;
 use agb::{
-    fixnum::{num, vec2},
+    fixnum::{Vector2D, num, vec2},
     include_aseprite,
 };
 
+use super::camera::{Camera, Layer};
 use super::config::GameConfig;
 
 include_aseprite!(
@@ -27,27 +28,48 @@ impl InventoryPresenter {
 
     pub fn update(&mut self) {}
 
+    fn show_ball(
+        &self,
+        frame: &mut GraphicsFrame,
+        camera: &Camera,
+        ball_data: &BallData,
+        slot: usize,
+    ) -> Vector2D<i32> {
+        let spr_id = ball_data.kind().sprite();
+        let mut sprite =
+            agb::display::object::Object::new(sprites::BALL.sprite(spr_id));
+        let position = self.position
+            + vec2(
+                num!(0),
+                Fixed::new(GameConfig::INVENTORY_ITEM_SPACING * slot as i32),
+            );
+        let position = camera.place(Layer::Hud, position.round());
+        sprite.set_pos(position).show(frame);
+        position
+    }
+
+    /// The loaded ball sits in the first slot, above the queue.
     pub fn show(
         &mut self,
         frame: &mut GraphicsFrame,
+        camera: &Camera,
+        loaded: Option<&BallData>,
         inventory: &[BallData],
         selected: usize,
         show_cursor: bool,
     ) {
+        if let Some(bd) = loaded {
+            let position = self.show_ball(frame, camera, bd, 0);
+            let mut marker = Object::new(sprites::LOADED.sprite(0));
+            marker.set_pos(position + vec2(-4, -4)).show(frame);
+        }
+
         for (i, bd) in inventory.iter().enumerate() {
-            let spr_id = bd.kind().sprite();
-            let mut sprite =
-                agb::display::object::Object::new(sprites::BALL.sprite(spr_id));
-            let position = self.position
-                + vec2(
-                    num!(0),
-                    Fixed::new(GameConfig::INVENTORY_ITEM_SPACING * i as i32),
-                );
-            sprite.set_pos(position.round()).show(frame);
+            let position = self.show_ball(frame, camera, bd, i + 1);
 
             if show_cursor && i == selected {
                 let mut cursor = Object::new(sprites::CURSOR.sprite(0));
-                cursor.set_pos(position.round() + vec2(-4, -4)).show(frame);
+                cursor.set_pos(position + vec2(-4, -4)).show(frame);
             }
         }
     }
@@ -1,11 +1,13 @@
-use crate::{Coordinates, Fixed};
+use crate::Coordinates;
+use crate::number::Number;
 use agb::display::GraphicsFrame;
 use agb::{
     display::object::Object,
-    fixnum::{num, vec2},
+    fixnum::{Vector2D, vec2},
     include_aseprite,
 };
 
+use super::camera::{Camera, Layer};
 use super::config::GameConfig;
 
 include_aseprite!(
@@ -43,51 +45,59 @@ impl<const MIN: i32, const MAX: i32> Jauge<MIN, MAX> {
         }
     }
 
-    pub fn set(&mut self, value: i32, min: i32, max: i32) {
-        let clamped = value.clamp(min, max);
-        let normalized = Fixed::new(clamped - min) / Fixed::new(max - min);
-        self.value = (normalized * num!(MAX - MIN)).floor() + MIN;
+    /// Works in `i64` since scores outgrow what `Fixed` can divide.
+    pub fn set(&mut self, value: impl Into<Number>, min: i32, max: i32) {
+        let clamped = value.into().clamp(min, max);
+        let filled = i64::from(clamped - min) * i64::from(MAX - MIN)
+            / i64::from((max - min).max(1));
+        self.value = filled as i32 + MIN;
     }
 
-    fn show_foreground(&mut self, frame: &mut GraphicsFrame) {
+    fn show_foreground(
+        &mut self,
+        frame: &mut GraphicsFrame,
+        position: Vector2D<i32>,
+    ) {
         const SPRL: i32 = GameConfig::JAUGE_SPRITE_WIDTH;
 
-        self.foreground_sprite
-            .0
-            .set_pos(self.position.round())
-            .show(frame);
+        self.foreground_sprite.0.set_pos(position).show(frame);
 
         self.foreground_sprite
             .1
-            .set_pos(self.position.round() + vec2(SPRL, 0))
+            .set_pos(position + vec2(SPRL, 0))
             .show(frame);
     }
 
-    fn show_mask(&mut self, frame: &mut GraphicsFrame) {
+    fn show_mask(
+        &mut self,
+        frame: &mut GraphicsFrame,
+        position: Vector2D<i32>,
+    ) {
         const SPRL: i32 = GameConfig::JAUGE_SPRITE_WIDTH;
-        let pos = self.position.round()
-            + vec2(self.value + GameConfig::JAUGE_PADDING, 0);
+        let pos = position + vec2(self.value + GameConfig::JAUGE_PADDING, 0);
 
         self.mask_sprite.0.set_pos(pos).show(frame);
         self.mask_sprite.1.set_pos(pos + vec2(SPRL, 0)).show(frame);
     }
 
-    fn show_background(&mut self, frame: &mut GraphicsFrame) {
+    fn show_background(
+        &mut self,
+        frame: &mut GraphicsFrame,
+        position: Vector2D<i32>,
+    ) {
         const SPRL: i32 = GameConfig::JAUGE_SPRITE_WIDTH;
 
-        self.background_sprite
-            .0
-            .set_pos(self.position.round())
-            .show(frame);
+        self.background_sprite.0.set_pos(position).show(frame);
         self.background_sprite
             .1
-            .set_pos(self.position.round() + vec2(SPRL, 0))
+            .set_pos(position + vec2(SPRL, 0))
             .show(frame);
     }
 
-    pub fn show(&mut self, frame: &mut GraphicsFrame) {
-        self.show_foreground(frame);
-        self.show_mask(frame);
-        self.show_background(frame);
+    pub fn show(&mut self, frame: &mut GraphicsFrame, camera: &Camera) {
+        let position = camera.place(Layer::Hud, self.position.round());
+        self.show_foreground(frame, position);
+        self.show_mask(frame, position);
+        self.show_background(frame, position);
     }
 }
@@ -6,6 +6,7 @@ use agb::{
     include_aseprite,
 };
 
+use super::camera::{Camera, Layer};
 use super::config::GameConfig;
 
 include_aseprite!(
@@ -99,7 +100,7 @@ impl Launcher {
         normalized_velocity * num!(0.25) + num!(0.75)
     }
 
-    pub fn show(&mut self, frame: &mut GraphicsFrame) {
+    pub fn show(&mut self, frame: &mut GraphicsFrame, camera: &Camera) {
         let affine_matrix: AffineMatrix =
             AffineMatrix::from_rotation(self.angle);
         let affine_matrix_instance = AffineMatrixObject::new(affine_matrix);
@@ -107,9 +108,12 @@ impl Launcher {
         self.sprite
             .set_affine_matrix(affine_matrix_instance)
             .set_pos(
-                (self.position + vec2(num!(4), num!(4))
-                    - vec2(num!(16), num!(16)))
-                .round(),
+                camera.place(
+                    Layer::Playfield,
+                    (self.position + vec2(num!(4), num!(4))
+                        - vec2(num!(16), num!(16)))
+                    .round(),
+                ),
             )
             .set_priority(agb::display::Priority::P3)
             .show(frame);
@@ -1,30 +1,37 @@
 use crate::level::Level;
+use crate::scenes::transition::{Kind, Transition};
 use crate::{error::Error, save::Save, scenes::Scene};
 use agb::input::{Button, ButtonController};
 
 mod background;
 mod ball;
 mod bucket;
-mod config;
+mod camera;
+pub mod config;
 mod counter;
-mod effect;
+pub mod effect;
 mod game_state;
 mod input;
 mod inventory;
 mod jauge;
 mod launcher;
+mod particles;
 mod peg;
 mod peg_generator;
 mod physics_handler;
 mod points_pres;
+pub mod relic;
 mod saved_pres;
 mod score;
+mod scoring;
 mod shake;
 mod state;
+mod trail;
 
 #[cfg(test)]
 mod test;
 
+use config::GameConfig;
 use game_state::GameState;
 use state::State;
 
@@ -39,13 +46,12 @@ fn update<const MAX_PEGS: usize>(
         State::Aiming => game_state.update_aiming(input)?,
         State::Falling => game_state.update_falling(input)?,
         State::InInventory => game_state.update_inventory(input)?,
+        State::Clearing { bucketed } => game_state.update_clearing(bucketed)?,
         State::Counting { bucketed } => {
             crate::bench::log();
             let res = game_state.update_counting(bucketed)?;
 
-            if let Some(ball_data) = bucketed {
-                game_state.add_bucketed_ball(ball_data);
-            }
+            game_state.end_shot(bucketed);
 
             if game_state.is_winning() {
                 return Ok(Scene::Drop);
@@ -74,12 +80,14 @@ pub fn main(
     gba: &mut agb::Gba,
     save: &mut Save,
     level: Level,
+    transition: &mut Transition,
 ) -> Result<Scene, Error> {
     let mut gfx = gba.graphics.get();
     let mut input = ButtonController::new();
     let mut timers = gba.timers.timers();
 
-    let mut game_state = GameState::<50>::new(save, level)?;
+    let mut game_state =
+        GameState::<{ GameConfig::MAX_PEGS }>::new(save, level)?;
     game_state.pop_ball()?;
 
     crate::bench::init(&mut timers);
@@ -89,13 +97,31 @@ pub fn main(
     crate::reset::reset_input(Button::A, &mut input, &mut gfx);
 
     loop {
-        match update(&mut game_state, &mut input) {
-            Ok(Scene::Game) => {}
-            res => return res,
+        if let Some(scene) = transition.update() {
+            return Ok(scene);
+        }
+
+        if !transition.is_leaving() {
+            match update(&mut game_state, &mut input)? {
+                Scene::Game => {}
+                Scene::Drop => {
+                    save.record_result(game_state.level_result(save));
+                    save.set_inventory(game_state.ball_order());
+                    save.set_relics(game_state.relics().iter().copied());
+                    save.set_coins(game_state.coins());
+                    transition.leave(Kind::FadeToWhite, Scene::Drop);
+                }
+                Scene::GameOver => {
+                    save.record_result(game_state.level_result(save));
+                    transition.leave(Kind::CircleIris, Scene::GameOver);
+                }
+                scene => transition.leave(Kind::CircleIris, scene),
+            }
         }
 
         let mut frame = gfx.frame();
-        game_state.show(&mut frame);
+        let bg_id = game_state.show(&mut frame);
+        transition.show(&mut frame, &[bg_id]);
         frame.commit();
     }
 }
@@ -0,0 +1,209 @@
+use crate::peg::Kind;
+use crate::{Coordinates, Fixed, Force};
+use agb::display::GraphicsFrame;
+use agb::display::object::{Object, Sprite};
+use agb::fixnum::{num, vec2};
+use agb::include_aseprite;
+use agb::rng::RandomNumberGenerator;
+use heapless::Vec;
+
+use super::camera::{Camera, Layer};
+use super::config::GameConfig;
+
+include_aseprite!(
+    mod sprites,
+    "assets/particles.aseprite",
+);
+
+/// What a burst of particles is for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Burst {
+    PegHit(Kind),
+    /// A green peg starting to grow new pegs.
+    Spores,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Look {
+    Shard(Kind),
+    Sparkle,
+    Coin,
+    Spore,
+}
+
+/// How one look is emitted. Speeds are in pixels per second, `lifetime` in
+/// seconds.
+struct Emission {
+    look: Look,
+    count: usize,
+    spread: Fixed,
+    lift: Fixed,
+    gravity: Fixed,
+    lifetime: Fixed,
+}
+
+impl Look {
+    fn sprite(self, frame: usize) -> &'static Sprite {
+        match self {
+            Look::Shard(kind) => sprites::SHARD.sprite(match kind {
+                Kind::Red => 0,
+                Kind::Blue => 1,
+                Kind::Yellow => 2,
+                Kind::Green => 3,
+            }),
+            Look::Sparkle => sprites::SPARKLE.animation_sprite(frame),
+            Look::Coin => sprites::COIN.animation_sprite(frame),
+            Look::Spore => sprites::SPORE.animation_sprite(frame),
+        }
+    }
+
+    fn emission(self) -> Emission {
+        match self {
+            Look::Shard(_) => Emission {
+                look: self,
+                count: 4,
+                spread: num!(60.0),
+                lift: num!(40.0),
+                gravity: num!(240.0),
+                lifetime: num!(0.5),
+            },
+            Look::Sparkle => Emission {
+                look: self,
+                count: 2,
+                spread: num!(25.0),
+                lift: num!(0.0),
+                gravity: num!(0.0),
+                lifetime: num!(0.3),
+            },
+            Look::Coin => Emission {
+                look: self,
+                count: 3,
+                spread: num!(30.0),
+                lift: num!(90.0),
+                gravity: num!(300.0),
+                lifetime: num!(0.6),
+            },
+            Look::Spore => Emission {
+                look: self,
+                count: 5,
+                spread: num!(20.0),
+                lift: num!(20.0),
+                gravity: num!(-20.0),
+                lifetime: num!(0.8),
+            },
+        }
+    }
+}
+
+impl Burst {
+    fn looks(self) -> &'static [Look] {
+        match self {
+            Burst::PegHit(Kind::Blue) => {
+                &[Look::Shard(Kind::Blue), Look::Sparkle]
+            }
+            Burst::PegHit(Kind::Red) => {
+                &[Look::Shard(Kind::Red), Look::Sparkle]
+            }
+            Burst::PegHit(Kind::Yellow) => {
+                &[Look::Shard(Kind::Yellow), Look::Sparkle, Look::Coin]
+            }
+            Burst::PegHit(Kind::Green) => {
+                &[Look::Shard(Kind::Green), Look::Sparkle]
+            }
+            Burst::Spores => &[Look::Spore],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Particle {
+    look: Look,
+    position: Coordinates,
+    velocity: Force,
+    gravity: Fixed,
+    lifetime: Fixed,
+}
+
+impl Particle {
+    /// Returns whether the particle is still alive.
+    fn update(&mut self) -> bool {
+        let delta: Fixed = num!(GameConfig::DELTA_TIME);
+        self.velocity.y += self.gravity * delta;
+        self.position += self.velocity * delta;
+        self.lifetime -= delta;
+        self.lifetime > num!(0)
+    }
+}
+
+/// Uniform in `[-amount, amount]`.
+fn spread(rng: &mut RandomNumberGenerator, amount: Fixed) -> Fixed {
+    let raw = amount.to_raw();
+    if raw <= 0 {
+        return num!(0);
+    }
+    Fixed::from_raw(rng.next_i32().rem_euclid(raw * 2 + 1) - raw)
+}
+
+/// A fixed pool of short-lived sprites. Bursts past the pool size, or past
+/// the spawn budget of a frame, are cut short so a long hit chain never
+/// costs more sprites or time than a quiet frame plus the pool.
+pub struct Particles {
+    pool: Vec<Particle, { GameConfig::PARTICLE_POOL_SIZE }>,
+    spawn_budget: usize,
+}
+
+impl Particles {
+    pub fn new() -> Self {
+        Self {
+            pool: Vec::new(),
+            spawn_budget: GameConfig::PARTICLE_SPAWN_BUDGET,
+        }
+    }
+
+    pub fn burst(
+        &mut self,
+        burst: Burst,
+        position: Coordinates,
+        rng: &mut RandomNumberGenerator,
+    ) {
+        for emission in burst.looks().iter().map(|look| look.emission()) {
+            for _ in 0..emission.count {
+                if self.spawn_budget == 0 || self.pool.is_full() {
+                    return;
+                }
+                self.spawn_budget -= 1;
+
+                let velocity = vec2(
+                    spread(rng, emission.spread),
+                    spread(rng, emission.spread) - emission.lift,
+                );
+                let _ = self.pool.push(Particle {
+                    look: emission.look,
+                    position,
+                    velocity,
+                    gravity: emission.gravity,
+                    lifetime: emission.lifetime,
+                });
+            }
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.spawn_budget = GameConfig::PARTICLE_SPAWN_BUDGET;
+        self.pool.retain_mut(Particle::update);
+    }
+
+    pub fn show(&self, frame: &mut GraphicsFrame, camera: &Camera) {
+        const HALF_SIZE: i32 = 4;
+
+        for particle in &self.pool {
+            // Animated looks flip frames a few times a second.
+            let step = (particle.lifetime * 8).floor() as usize;
+            let position =
+                particle.position.round() - vec2(HALF_SIZE, HALF_SIZE);
+            Object::new(particle.look.sprite(step))
+                .set_pos(camera.place(Layer::Playfield, position))
+                .show(frame);
+        }
+    }
+}
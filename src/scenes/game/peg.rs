@@ -1,10 +1,12 @@
 use crate::{Coordinates, Fixed, Force, level::Level, peg::Kind};
 use agb::display::GraphicsFrame;
-use agb::display::object::{Object, Sprite};
+use agb::display::object::{GraphicsMode, Object, Sprite};
 use agb::fixnum::{num, vec2};
 use agb::include_aseprite;
 use agb::rng::RandomNumberGenerator;
+use heapless::Deque;
 
+use super::camera::{Camera, Layer};
 use super::config::GameConfig;
 
 include_aseprite!(
@@ -29,6 +31,9 @@ pub struct Pegs<const N: usize> {
     pub showable: [bool; N],
     pub collidable: [bool; N],
     pub kind: [Kind; N],
+    /// Pegs hit during the current shot, first hit first. They stay shown,
+    /// blended, until cleared.
+    lit: Deque<usize, N>,
 }
 
 impl<const N: usize> Pegs<N> {
@@ -50,9 +55,26 @@ impl<const N: usize> Pegs<N> {
             showable,
             collidable,
             kind,
+            lit: Deque::new(),
         }
     }
 
+    /// Takes a peg out of play while keeping it on screen until the end of
+    /// the shot.
+    pub fn light(&mut self, i: usize) {
+        if self.collidable[i] {
+            self.collidable[i] = false;
+            let _ = self.lit.push_back(i);
+        }
+    }
+
+    /// Hides the earliest lit peg still shown and returns it.
+    pub fn clear_next(&mut self) -> Option<usize> {
+        let i = self.lit.pop_front()?;
+        self.showable[i] = false;
+        Some(i)
+    }
+
     pub fn spawn_pegs<const WALL_LEFT: i32, const WALL_RIGHT: i32>(
         rng: &mut RandomNumberGenerator,
         level: &Level,
@@ -88,10 +110,20 @@ impl<const N: usize> Pegs<N> {
         Pegs::new(positions, force_radius_squared, showable, collidable, kind)
     }
 
-    pub fn show(&mut self, frame: &mut GraphicsFrame) {
+    pub fn show(&mut self, frame: &mut GraphicsFrame, camera: &Camera) {
         for i in 0..N {
             if self.showable[i] {
-                self.sprite[i].set_pos(self.positions[i].round());
+                let mode = if self.collidable[i] {
+                    GraphicsMode::Normal
+                } else {
+                    GraphicsMode::AlphaBlending
+                };
+                self.sprite[i]
+                    .set_pos(
+                        camera
+                            .place(Layer::Playfield, self.positions[i].round()),
+                    )
+                    .set_graphics_mode(mode);
                 self.sprite[i].show(frame);
             }
         }
@@ -19,6 +19,7 @@ const PEG_CONFIG: PhysicsConfig = PhysicsConfig {
     gravity: 0, // Pegs don't use gravity
     repulsion_strength: GameConfig::PHYSICS_REPULSION_STRENGTH,
     object_radius: GameConfig::PHYSICS_OBJECT_RADIUS_PEG,
+    jitter: 0, // Set per level, see `update_pegs`
 };
 
 const BALL_CONFIG: PhysicsConfig = PhysicsConfig {
@@ -31,12 +32,15 @@ const BALL_CONFIG: PhysicsConfig = PhysicsConfig {
     gravity: GameConfig::PHYSICS_GRAVITY,
     repulsion_strength: 0, // Ball doesn't use repulsion
     object_radius: GameConfig::PHYSICS_OBJECT_RADIUS_BALL,
+    jitter: 0, // Ball doesn't jitter
 };
 
 impl PhysicsHandler {
     pub fn update_pegs<const MAX_PEGS: usize>(
         physics: &mut Physics<MAX_PEGS>,
         pegs: &mut Pegs<MAX_PEGS>,
+        rng: &mut RandomNumberGenerator,
+        jitter: i32,
     ) -> Result<(), Error> {
         crate::bench::start("PEG_UPDATE");
         let result = physics.move_from_fields::<15>(
@@ -45,7 +49,11 @@ impl PhysicsHandler {
             &pegs.collidable,
             &pegs.force_radius_squared,
             num!(GameConfig::DELTA_TIME),
-            &PEG_CONFIG,
+            &PhysicsConfig {
+                jitter,
+                ..PEG_CONFIG
+            },
+            rng,
         );
         crate::bench::stop("PEG_UPDATE");
         result
@@ -72,6 +80,24 @@ impl PhysicsHandler {
         Ok((result.0, result.1, result.2.to_vec()))
     }
 
+    /// Side walls clamp the ball against them and send it back the other way.
+    pub fn bounced_off_side_wall(
+        position: Coordinates,
+        old_velocity: Force,
+        new_velocity: Force,
+    ) -> bool {
+        let radius = Fixed::new(GameConfig::BALL_RADIUS);
+        let left = Fixed::new(GameConfig::WALL_LEFT) + radius;
+        let right = Fixed::new(GameConfig::WALL_RIGHT) - radius;
+
+        (position.x <= left
+            && old_velocity.x < num!(0)
+            && new_velocity.x > num!(0))
+            || (position.x >= right
+                && old_velocity.x > num!(0)
+                && new_velocity.x < num!(0))
+    }
+
     pub fn spawn_single_peg_from_green<const MAX_PEGS: usize>(
         pegs: &mut Pegs<MAX_PEGS>,
         physics: &mut Physics<MAX_PEGS>,
@@ -100,17 +126,4 @@ impl PhysicsHandler {
         }
         Ok(false)
     }
-
-    pub fn hide_non_collidable_pegs<const MAX_PEGS: usize>(
-        pegs: &mut Pegs<MAX_PEGS>,
-    ) {
-        pegs.collidable
-            .iter()
-            .zip(pegs.showable.iter_mut())
-            .for_each(|(&is_collidable, showable)| {
-                if !is_collidable {
-                    *showable = false;
-                }
-            });
-    }
 }
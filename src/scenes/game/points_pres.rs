@@ -1,16 +1,19 @@
+use super::camera::{Camera, Layer};
 use super::score::Score;
 use crate::peg::Kind;
 use agb::{
     display::{
         GraphicsFrame,
-        object::{Object, Sprite},
+        object::{Object, Sprite, Tag},
     },
     fixnum::{Vector2D, num, vec2},
     include_aseprite,
 };
-use alloc::vec::Vec;
 
 use crate::Coordinates;
+use crate::number::{self, Number, Sign};
+
+const MAX_WIDTH: usize = 5;
 
 include_aseprite!(
     mod sprites,
@@ -27,7 +30,7 @@ fn get_bg_sprite(kind: Kind) -> &'static Sprite {
     }
 }
 
-fn get_value(score: Score, kind: Kind) -> Option<i32> {
+fn get_value(score: Score, kind: Kind) -> Option<Number> {
     match kind {
         Kind::Blue => Some(score.base),
         Kind::Red => Some(score.mult),
@@ -36,9 +39,29 @@ fn get_value(score: Score, kind: Kind) -> Option<i32> {
     }
 }
 
+/// Sprite set a popup draws its digits with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Colour {
+    Black,
+    Red,
+    White,
+}
+
+impl Colour {
+    fn tag(self) -> &'static Tag {
+        match self {
+            Colour::Black => &sprites::DIGITS,
+            Colour::Red => &sprites::DIGITSRED,
+            Colour::White => &sprites::DIGITSWHITE,
+        }
+    }
+}
+
 pub struct PointsPres {
     base_position: Coordinates,
-    sprites: Vec<(Object, Object)>,
+    value: Number,
+    background: &'static Sprite,
+    colour: Colour,
     frames: i32,
 }
 
@@ -48,39 +71,44 @@ impl PointsPres {
         score: Score,
         kind: Kind,
     ) -> Option<Self> {
-        let value = get_value(score, kind)?;
-        let mut sprites = Vec::new();
-
-        let mut div = 1;
-        for _ in 0..(value / 10 + 1) {
-            let spr_id = (value / div) % 10;
-            sprites.push((
-                Object::new(sprites::DIGITS.sprite(spr_id as usize)),
-                Object::new(get_bg_sprite(kind)),
-            ));
-            div *= 10;
-        }
-
         Some(Self {
             base_position: position,
-            sprites,
+            value: get_value(score, kind)?,
+            background: get_bg_sprite(kind),
+            colour: Colour::Black,
             frames: 20,
         })
     }
 
+    pub fn set_colour(&mut self, colour: Colour) {
+        self.colour = colour;
+    }
+
     pub fn update(&mut self) -> bool {
         self.frames -= 1;
         self.base_position -= vec2(num!(0), num!(0.25));
         self.frames > 0
     }
 
-    pub fn show(&mut self, frame: &mut GraphicsFrame) {
-        for (i, (nb, bg)) in self.sprites.iter_mut().rev().enumerate() {
+    /// Negative values are always drawn in red.
+    pub fn show(&mut self, frame: &mut GraphicsFrame, camera: &Camera) {
+        let colour = if self.value < Number::ZERO {
+            Colour::Red
+        } else {
+            self.colour
+        };
+        let position =
+            camera.place(Layer::Playfield, self.base_position.round());
+        let glyphs = number::glyphs(self.value, MAX_WIDTH, Sign::NegativeOnly);
+
+        for (i, glyph) in glyphs.iter().enumerate() {
             let padding = Vector2D::new(i as i32 * 4, 0);
-            nb.set_pos(self.base_position.round() + padding);
-            bg.set_pos(self.base_position.round() + padding);
-            nb.show(frame);
-            bg.show(frame);
+            Object::new(colour.tag().sprite(glyph.frame()))
+                .set_pos(position + padding)
+                .show(frame);
+            Object::new(self.background)
+                .set_pos(position + padding)
+                .show(frame);
         }
     }
 }
@@ -0,0 +1,96 @@
+use crate::Coordinates;
+use crate::peg::Kind;
+use crate::save::RelicKind;
+use crate::scenes::game::score::Score;
+use crate::scenes::game::scoring::Event;
+use agb::display::GraphicsFrame;
+use agb::display::object::Object;
+use agb::fixnum::vec2;
+use agb::include_aseprite;
+use alloc::format;
+use alloc::string::String;
+
+use super::camera::{Camera, Layer};
+use super::config::GameConfig;
+
+include_aseprite!(
+    mod sprites,
+    "assets/relics.aseprite",
+    "assets/ball_cursor.aseprite",
+);
+
+/// Scoring hook. A relic that does not react to an event returns the score
+/// unchanged.
+impl RelicKind {
+    pub fn on_event(self, event: Event, score: Score) -> Score {
+        match (self, event) {
+            (RelicKind::Whetstone, Event::PegHit(Kind::Blue)) => {
+                score.add(2, 0, 0)
+            }
+            (RelicKind::RubyLens, Event::PegHit(Kind::Red)) => {
+                score.add(0, 1, 0)
+            }
+            (RelicKind::Bellows, Event::Bucket) => score.add(0, 3, 0),
+            (RelicKind::TuningFork, Event::ShotEnd) => score.add(0, 2, 0),
+            (RelicKind::Echo, Event::ShotEnd) => score.mult(1, 2, 1),
+            (RelicKind::PiggyBank, Event::LevelStart) => score.add(0, 0, 3),
+            _ => score,
+        }
+    }
+
+    fn effect(self) -> &'static str {
+        match self {
+            RelicKind::Whetstone => "+2 {blue}base{white} on [blue] pegs",
+            RelicKind::RubyLens => "+1 {red}mult{white} on [red] pegs",
+            RelicKind::Bellows => "+3 {red}mult{white} when bucketed",
+            RelicKind::TuningFork => "+2 {red}mult{white} at end of shot",
+            RelicKind::Echo => "x2 {red}mult{white} at end of shot",
+            RelicKind::PiggyBank => "+3 [coin] at level start",
+        }
+    }
+
+    /// Same layout as `BallData::description`.
+    pub fn description(self) -> String {
+        format!(
+            "{{yellow}}{}{{white}}\n{}\n{}",
+            self.name(),
+            self.effect(),
+            self.flavour()
+        )
+    }
+}
+
+pub struct RelicPresenter {
+    position: Coordinates,
+}
+
+impl RelicPresenter {
+    pub fn new(position: Coordinates) -> Self {
+        Self { position }
+    }
+
+    pub fn show(
+        &self,
+        frame: &mut GraphicsFrame,
+        camera: &Camera,
+        relics: &[RelicKind],
+        selected: Option<usize>,
+    ) {
+        for (i, relic) in relics.iter().enumerate() {
+            let position = camera.place(
+                Layer::Hud,
+                self.position.round()
+                    + vec2(GameConfig::RELIC_SPACING * i as i32, 0),
+            );
+            Object::new(sprites::RELIC.sprite(relic.sprite()))
+                .set_pos(position)
+                .show(frame);
+
+            if selected == Some(i) {
+                Object::new(sprites::CURSOR.sprite(0))
+                    .set_pos(position + vec2(-4, -4))
+                    .show(frame);
+            }
+        }
+    }
+}
@@ -6,6 +6,8 @@ use agb::{
 
 use crate::Coordinates;
 
+use super::camera::{Camera, Layer};
+
 include_aseprite!(
     mod sprites,
     "assets/saved.aseprite",
@@ -32,8 +34,10 @@ impl SavedPres {
         self.frames > 0
     }
 
-    pub fn show(&mut self, frame: &mut GraphicsFrame) {
-        self.sprite.set_pos(self.base_position.round());
+    pub fn show(&mut self, frame: &mut GraphicsFrame, camera: &Camera) {
+        self.sprite.set_pos(
+            camera.place(Layer::Playfield, self.base_position.round()),
+        );
         self.sprite.set_priority(agb::display::Priority::P0);
         self.sprite.show(frame);
     }
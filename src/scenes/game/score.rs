@@ -1,36 +1,36 @@
-use crate::peg::Kind;
+use crate::number::Number;
 use crate::scenes::game::counter::Counter;
-use crate::scenes::game::effect::{BallData, BucketEffect};
+use crate::scenes::game::scoring::{Event, Pipeline, Trace};
 
-pub type Component = i32;
-pub type Damage = i32;
+pub type Component = Number;
+pub type Damage = Number;
 pub type Coins = i32;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Score {
     pub base: Component,
     pub mult: Component,
-    pub coins: Coins,
+    pub coins: Component,
 }
 
 impl Score {
-    pub fn new(base: Component, mult: Component, coins: Coins) -> Self {
-        Self { base, mult, coins }
-    }
-
-    pub fn add(self, base: Component, mult: Component, coins: Coins) -> Self {
+    pub fn new(base: i32, mult: i32, coins: i32) -> Self {
         Self {
-            base: self.base + base,
-            mult: self.mult + mult,
-            coins: self.coins + coins,
+            base: base.into(),
+            mult: mult.into(),
+            coins: coins.into(),
         }
     }
 
-    pub fn mult(self, base: Component, mult: Component, coins: Coins) -> Self {
+    pub fn add(self, base: i32, mult: i32, coins: i32) -> Self {
+        self.apply(Score::new(base, mult, coins))
+    }
+
+    pub fn mult(self, base: i32, mult: i32, coins: i32) -> Self {
         Self {
-            base: self.base * base,
-            mult: self.mult * mult,
-            coins: self.coins * coins,
+            base: self.base * base.into(),
+            mult: self.mult * mult.into(),
+            coins: self.coins * coins.into(),
         }
     }
 
@@ -43,7 +43,7 @@ impl Score {
     }
 
     pub fn extract(self) -> (Damage, Coins) {
-        (self.base * self.mult, self.coins)
+        (self.base * self.mult, self.coins.get())
     }
 }
 
@@ -51,7 +51,9 @@ pub struct ScoreManager {
     target_score: i32,
     current_score: Option<Score>,
     damages: Damage,
+    best_shot: Damage,
     coins: Coins,
+    trace: Trace,
 }
 
 impl ScoreManager {
@@ -59,8 +61,10 @@ impl ScoreManager {
         Self {
             target_score,
             current_score: None,
-            damages: 0,
+            damages: Number::ZERO,
+            best_shot: Number::ZERO,
             coins,
+            trace: Trace::new(),
         }
     }
 
@@ -68,59 +72,50 @@ impl ScoreManager {
         self.target_score
     }
 
-    pub fn process_peg_hit(
+    /// Runs an event that scores on its own, like a peg hit, and adds the
+    /// result to the current shot. Returns what the event was worth.
+    pub fn process_hit(
         &mut self,
-        peg_kind: Kind,
-        inventory: &[BallData],
-        current_ball_data: &Option<BallData>,
+        event: Event,
+        pipeline: &Pipeline,
         mult_counter: &mut Counter,
         base_counter: &mut Counter,
         coin_counter: &mut Counter,
     ) -> Score {
         let old_score = self.current_score.unwrap_or(Score::new(0, 1, 0));
-        let mut cur_score = Score::new(0, 0, 0);
-
-        cur_score = cur_score.apply(match peg_kind {
-            Kind::Blue => Score::new(1, 0, 0),
-            Kind::Red => Score::new(0, 1, 0),
-            Kind::Yellow => Score::new(0, 0, 1),
-            Kind::Green => Score::new(0, 0, 0),
-        });
-
-        for pe in inventory {
-            cur_score = pe.passive().apply(cur_score);
-        }
-
-        if let Some(ball_data) = current_ball_data {
-            cur_score = ball_data.active().apply(cur_score, peg_kind);
-        }
-
+        let cur_score =
+            pipeline.run(event, Score::new(0, 0, 0), &mut self.trace);
         let new_score = old_score.apply(cur_score);
 
-        mult_counter.set(new_score.mult);
-        base_counter.set(new_score.base);
-        coin_counter.set(self.coins + new_score.coins);
-        self.current_score = Some(new_score);
-
+        self.set_score(new_score, mult_counter, base_counter, coin_counter);
         cur_score
     }
 
-    pub fn process_bucket_bonus(
+    /// Runs an event that transforms the whole shot, like the bucket bonus.
+    pub fn process_shot_event(
         &mut self,
-        bucket_effects: &[BucketEffect],
+        event: Event,
+        pipeline: &Pipeline,
         mult_counter: &mut Counter,
         base_counter: &mut Counter,
         coin_counter: &mut Counter,
     ) {
-        let mut score = self.current_score.unwrap_or(Score::new(0, 1, 0));
+        let score = self.current_score.unwrap_or(Score::new(0, 1, 0));
+        let score = pipeline.run(event, score, &mut self.trace);
 
-        for e in bucket_effects {
-            score = e.apply(score);
-        }
+        self.set_score(score, mult_counter, base_counter, coin_counter);
+    }
 
+    fn set_score(
+        &mut self,
+        score: Score,
+        mult_counter: &mut Counter,
+        base_counter: &mut Counter,
+        coin_counter: &mut Counter,
+    ) {
         mult_counter.set(score.mult);
         base_counter.set(score.base);
-        coin_counter.set(self.coins + score.coins);
+        coin_counter.set(Number::from(self.coins) + score.coins);
         self.current_score = Some(score);
     }
 
@@ -128,16 +123,31 @@ impl ScoreManager {
         if let Some(score) = self.current_score {
             let (damages, coins) = score.extract();
             self.damages += damages;
-            self.coins += coins;
+            self.best_shot = self.best_shot.max(damages);
+            self.coins = self.coins.saturating_add(coins);
             self.current_score = None;
+            self.trace.flush();
             (self.damages, self.coins)
         } else {
             (self.damages, self.coins)
         }
     }
 
+    pub fn damages(&self) -> Damage {
+        self.damages
+    }
+
+    /// The most damage a single shot dealt this level.
+    pub fn best_shot(&self) -> Damage {
+        self.best_shot
+    }
+
+    pub fn coins(&self) -> Coins {
+        self.coins
+    }
+
     pub fn is_winning(&self) -> bool {
-        self.damages > self.target_score
+        self.damages > Number::from(self.target_score)
     }
 
     pub fn reset_counters(
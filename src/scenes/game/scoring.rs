@@ -0,0 +1,150 @@
+use crate::level::LevelModifier;
+use crate::peg::Kind;
+use crate::save::RelicKind;
+use crate::scenes::game::effect::{
+    ActiveEffect, BallData, BucketEffect, PassiveEffect,
+};
+use crate::scenes::game::score::Score;
+use alloc::vec::Vec;
+
+const TRACE_CAPACITY: usize = 64;
+
+/// Moments of a shot that handlers can react to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Event {
+    PegHit(Kind),
+    WallBounce,
+    Bucket,
+    ShotEnd,
+    LevelStart,
+}
+
+/// Anything that can change a score. Handlers run in the order of this
+/// enum: pegs, then balls, then bucket effects, relics and level modifiers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Handler {
+    Peg,
+    Passive(PassiveEffect),
+    Active(ActiveEffect),
+    Bucket(BucketEffect),
+    Relic(RelicKind),
+    Level(LevelModifier),
+}
+
+impl Handler {
+    fn handle(self, event: Event, score: Score) -> Score {
+        match (self, event) {
+            (Handler::Peg, Event::PegHit(kind)) => score.apply(match kind {
+                Kind::Blue => Score::new(1, 0, 0),
+                Kind::Red => Score::new(0, 1, 0),
+                Kind::Yellow => Score::new(0, 0, 1),
+                Kind::Green => Score::new(0, 0, 0),
+            }),
+            (Handler::Passive(effect), Event::PegHit(_)) => effect.apply(score),
+            (Handler::Active(effect), Event::PegHit(kind)) => {
+                effect.apply(score, kind)
+            }
+            (Handler::Bucket(effect), Event::Bucket) => effect.apply(score),
+            (Handler::Relic(relic), event) => relic.on_event(event, score),
+            (Handler::Level(modifier), event) => {
+                modifier.on_event(event, score)
+            }
+            _ => score,
+        }
+    }
+}
+
+impl LevelModifier {
+    pub fn on_event(self, event: Event, score: Score) -> Score {
+        match (self, event) {
+            (LevelModifier::PaddedWalls(b), Event::WallBounce) => {
+                score.add(b, 0, 0)
+            }
+            _ => score,
+        }
+    }
+}
+
+/// One handler changing the score, kept for the debug console.
+#[derive(Clone, Copy, Debug)]
+pub struct Step {
+    pub event: Event,
+    pub handler: Handler,
+    pub before: Score,
+    pub after: Score,
+}
+
+/// Records steps only when built with the `score-trace` feature.
+pub struct Trace {
+    steps: Vec<Step>,
+}
+
+impl Trace {
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    fn push(&mut self, step: Step) {
+        if cfg!(feature = "score-trace") && self.steps.len() < TRACE_CAPACITY {
+            self.steps.push(step);
+        }
+    }
+
+    /// Prints the recorded steps and starts over.
+    pub fn flush(&mut self) {
+        for step in self.steps.drain(..) {
+            agb::println!(
+                "{:?} {:?}: {:?} -> {:?}",
+                step.event,
+                step.handler,
+                step.before,
+                step.after
+            );
+        }
+    }
+}
+
+/// Everything that reacts to scoring events during a shot.
+pub struct Pipeline<'a> {
+    pub inventory: &'a [BallData],
+    pub current_ball: Option<&'a BallData>,
+    pub bucket_effects: &'a [BucketEffect],
+    pub relics: &'a [RelicKind],
+    pub modifiers: &'a [LevelModifier],
+}
+
+impl Pipeline<'_> {
+    fn handlers(&self) -> impl Iterator<Item = Handler> + '_ {
+        core::iter::once(Handler::Peg)
+            .chain(
+                self.inventory
+                    .iter()
+                    .map(|ball| Handler::Passive(ball.passive())),
+            )
+            .chain(self.current_ball.map(|ball| Handler::Active(ball.active())))
+            .chain(self.bucket_effects.iter().copied().map(Handler::Bucket))
+            .chain(self.relics.iter().copied().map(Handler::Relic))
+            .chain(self.modifiers.iter().copied().map(Handler::Level))
+    }
+
+    pub fn run(
+        &self,
+        event: Event,
+        mut score: Score,
+        trace: &mut Trace,
+    ) -> Score {
+        for handler in self.handlers() {
+            let after = handler.handle(event, score);
+            if after != score {
+                trace.push(Step {
+                    event,
+                    handler,
+                    before: score,
+                    after,
+                });
+            }
+            score = after;
+        }
+        score
+    }
+}
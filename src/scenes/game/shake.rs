@@ -1,51 +1,81 @@
-use crate::Coordinates;
+use crate::{Coordinates, Fixed, Force};
 use agb::{
     fixnum::{num, vec2},
     rng::RandomNumberGenerator,
 };
 
+/// Offsets that fade out over `duration` frames, either in any direction
+/// or swinging back and forth along one.
 #[derive(Debug, Clone, Copy)]
 pub struct ScreenShake {
     duration: u32,
+    remaining: u32,
     intensity: i32,
+    /// Unit vector to swing along, any direction when `None`.
+    direction: Option<Force>,
     offset: Coordinates,
 }
 
 impl ScreenShake {
-    pub fn new(duration: u32, intensity: i32) -> Self {
+    pub fn inactive() -> Self {
         Self {
-            duration,
-            intensity,
+            duration: 0,
+            remaining: 0,
+            intensity: 0,
+            direction: None,
             offset: vec2(num!(0), num!(0)),
         }
     }
 
-    pub fn inactive() -> Self {
-        Self::new(0, 0)
-    }
-
-    pub fn is_active(&self) -> bool {
-        self.duration > 0
+    /// The intensity, scaled down linearly as the shake runs out.
+    fn amplitude(&self) -> Fixed {
+        Fixed::new(self.intensity) * self.remaining as i32
+            / self.duration as i32
     }
 
     pub fn update(&mut self, rng: &mut RandomNumberGenerator) {
-        if self.duration > 0 {
-            self.duration -= 1;
-
-            let x_offset = rng.next_i32().abs() % (self.intensity * 2 + 1)
-                - self.intensity;
-            let y_offset = rng.next_i32().abs() % (self.intensity * 2 + 1)
-                - self.intensity;
-
-            self.offset = vec2(x_offset.into(), y_offset.into());
-        } else {
+        if self.remaining == 0 {
             self.offset = vec2(num!(0), num!(0));
+            return;
         }
+
+        let amplitude = self.amplitude();
+        self.remaining -= 1;
+        let elapsed = self.duration - self.remaining;
+
+        // Directional shakes start along `direction` then swing back.
+        self.offset = match self.direction {
+            Some(direction) if elapsed % 2 == 1 => direction * amplitude,
+            Some(direction) => -direction * amplitude,
+            None => {
+                let range = amplitude.floor() * 2 + 1;
+                let x_offset = rng.next_i32().abs() % range;
+                let y_offset = rng.next_i32().abs() % range;
+                vec2(x_offset.into(), y_offset.into())
+                    - vec2(amplitude, amplitude)
+            }
+        };
     }
 
+    /// Shakes in every direction.
     pub fn start(&mut self, duration: u32, intensity: i32) {
         self.duration = duration;
+        self.remaining = duration;
         self.intensity = intensity;
+        self.direction = None;
+    }
+
+    /// Shakes back and forth along `direction`, like a recoil.
+    pub fn start_along(
+        &mut self,
+        duration: u32,
+        intensity: i32,
+        direction: Force,
+    ) {
+        self.start(duration, intensity);
+        if direction.magnitude_squared() > num!(0) {
+            self.direction = Some(direction.normalise());
+        }
     }
 
     pub fn offset(&self) -> Coordinates {
@@ -77,4 +107,3 @@ impl WhiteFlash {
         self.duration = duration;
     }
 }
-
@@ -6,7 +6,13 @@ pub enum State {
     Aiming,
     Falling,
     InInventory,
-    Counting { bucketed: Option<BallData> },
+    /// Lit pegs are cleared one by one before the shot is counted.
+    Clearing {
+        bucketed: Option<BallData>,
+    },
+    Counting {
+        bucketed: Option<BallData>,
+    },
 }
 
 pub struct StateManager {
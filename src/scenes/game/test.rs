@@ -1,17 +1,20 @@
-// This module is only compiled during tests
-// rust-analyzer should respect this and not show errors during normal editing
+// This module is only compiled during tests, see `#[cfg(test)]` on its
+// declaration.
 
-#![cfg(test)]
 // Disable clippy warnings for test-only code
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
 use crate::Fixed;
+use crate::peg::Kind;
+use crate::physics::golden;
+use crate::physics::scenario::{self, Sample};
 use crate::physics::{Physics, PhysicsConfig};
-use crate::scenes::game::peg::{FORCE_RADII, Kind};
+use crate::scenes::game::config::GameConfig;
 use agb::Gba;
 use agb::fixnum::{num, vec2};
 use agb::rng::RandomNumberGenerator;
+use alloc::vec::Vec;
 
 #[test_case]
 pub fn test_physics_performance(gba: &mut Gba) {
@@ -39,9 +42,11 @@ pub fn test_physics_performance(gba: &mut Gba) {
         let x = min_x + (rng.next_i32().abs() % (screen_width - min_x));
         let y = min_y + (rng.next_i32().abs() % (screen_height - min_y));
 
-        let force_radius_index =
-            (rng.next_i32().abs() % FORCE_RADII.len() as i32) as usize;
-        let force_radius = Fixed::new(FORCE_RADII[force_radius_index] as i32);
+        let force_radius_index = (rng.next_i32().abs()
+            % GameConfig::PEG_FORCE_RADII.len() as i32)
+            as usize;
+        let force_radius =
+            Fixed::new(GameConfig::PEG_FORCE_RADII[force_radius_index] as i32);
 
         positions[i] = vec2(Fixed::new(x), Fixed::new(y));
         force_radius_squared[i] = force_radius * force_radius;
@@ -70,6 +75,7 @@ pub fn test_physics_performance(gba: &mut Gba) {
         gravity: 0,
         repulsion_strength: 3000,
         object_radius: 4,
+        jitter: 5,
     };
     crate::bench::stop("PHYSICS_INIT");
 
@@ -77,7 +83,7 @@ pub fn test_physics_performance(gba: &mut Gba) {
     let delta_time = num!(1.0 / 60.0);
 
     crate::bench::start("PHYSICS_SIMULATION");
-    for frame in 0..simulation_frames {
+    for _frame in 0..simulation_frames {
         crate::bench::start("PHYSICS_FRAME");
 
         physics
@@ -88,6 +94,7 @@ pub fn test_physics_performance(gba: &mut Gba) {
                 &force_radius_squared,
                 delta_time,
                 &test_config,
+                &mut rng,
             )
             .expect("Physics frame failed");
 
@@ -95,12 +102,11 @@ pub fn test_physics_performance(gba: &mut Gba) {
     }
     crate::bench::stop("PHYSICS_SIMULATION");
 
-    let mut moved_pegs = 0;
-    for i in 0..PEG_COUNT {
-        if velocities[i].x != num!(0) || velocities[i].y != num!(0) {
-            moved_pegs += 1;
-        }
-    }
+    let moved_pegs = velocities[..PEG_COUNT]
+        .iter()
+        .filter(|velocity| velocity.x != num!(0) || velocity.y != num!(0))
+        .count();
+    assert!(moved_pegs > 0, "no peg moved out of {PEG_COUNT}");
 
     crate::bench::log();
 }
@@ -127,9 +133,23 @@ pub fn test_physics_collision_accuracy(gba: &mut Gba) {
     velocities[0] = vec2(num!(1), num!(0));
     velocities[1] = vec2(num!(-1), num!(0));
 
+    let test_config = PhysicsConfig {
+        left_wall: 10,
+        up_wall: 10,
+        right_wall: 150,
+        down_wall: 110,
+        moving_radius: 5,
+        static_radius: 5,
+        gravity: 0,
+        repulsion_strength: 3000,
+        object_radius: 5,
+        jitter: 5,
+    };
+
     crate::bench::start("COLLISION_TEST");
     let mut physics = Physics::<MAX_PEGS>::new(&positions, &collidable)
         .expect("Physics init failed");
+    let mut rng = RandomNumberGenerator::new();
 
     for _step in 0..10 {
         physics
@@ -139,13 +159,362 @@ pub fn test_physics_collision_accuracy(gba: &mut Gba) {
                 &collidable,
                 &force_radius_squared,
                 num!(1.0 / 60.0),
+                &test_config,
+                &mut rng,
             )
             .expect("Physics step failed");
     }
     crate::bench::stop("COLLISION_TEST");
 
     let initial_pos = vec2(num!(50), num!(50));
-    let pos_changed = positions[0] != initial_pos;
+    assert!(positions[0] != initial_pos, "colliding peg did not move");
 
     crate::bench::log();
 }
+
+/// Compares a replayed scenario with its recording, frame by frame. On a
+/// mismatch the new trajectory is printed in the format of `golden`.
+fn check_golden(
+    name: &str,
+    bodies: usize,
+    recorded: Vec<Sample>,
+    golden: &[Sample],
+) {
+    let frames = golden.chunks(bodies).zip(recorded.chunks(bodies));
+    let diverged = frames
+        .enumerate()
+        .find(|(_, (expected, actual))| expected != actual)
+        .map(|(frame, _)| frame);
+
+    if diverged.is_none() && recorded.len() == golden.len() {
+        return;
+    }
+
+    agb::println!("pub const {}: &[Sample] = &[", name);
+    for sample in &recorded {
+        agb::println!(
+            "    [{}, {}, {}, {}],",
+            sample[0],
+            sample[1],
+            sample[2],
+            sample[3]
+        );
+    }
+    agb::println!("];");
+    panic!(
+        "{} diverged from its golden trajectory at frame {:?} ({} samples, \
+         {} recorded)",
+        name,
+        diverged,
+        recorded.len(),
+        golden.len()
+    );
+}
+
+#[test_case]
+fn test_golden_ball_vs_single_peg(_gba: &mut Gba) {
+    check_golden(
+        "BALL_VS_SINGLE_PEG",
+        1,
+        scenario::ball_vs_single_peg(),
+        golden::BALL_VS_SINGLE_PEG,
+    );
+}
+
+#[test_case]
+fn test_golden_corner_escape(_gba: &mut Gba) {
+    check_golden(
+        "CORNER_ESCAPE",
+        1,
+        scenario::corner_escape(),
+        golden::CORNER_ESCAPE,
+    );
+}
+
+#[test_case]
+fn test_golden_bucket_wall_hits(_gba: &mut Gba) {
+    check_golden(
+        "BUCKET_WALL_HITS",
+        1,
+        scenario::bucket_wall_hits(),
+        golden::BUCKET_WALL_HITS,
+    );
+}
+
+#[test_case]
+fn test_golden_dense_peg_cluster(_gba: &mut Gba) {
+    check_golden(
+        "DENSE_PEG_CLUSTER",
+        scenario::DENSE_PEG_COUNT,
+        scenario::dense_peg_cluster(),
+        golden::DENSE_PEG_CLUSTER,
+    );
+}
+
+#[test_case]
+fn test_camera_shake_decays(_gba: &mut Gba) {
+    use crate::scenes::game::camera::{Camera, Layer};
+
+    let mut rng = RandomNumberGenerator::new();
+    let mut camera = Camera::new();
+    camera.shake_along(4, 8, vec2(num!(0), num!(-3)));
+
+    let mut swings = Vec::new();
+    for _ in 0..6 {
+        camera.update(&mut rng);
+        assert_eq!(camera.offset(Layer::Hud), vec2(0, 0));
+        let offset = camera.offset(Layer::Playfield);
+        assert_eq!(offset.x, 0);
+        swings.push(offset.y);
+    }
+
+    assert_eq!(swings[..4], [-8, 6, -4, 2]);
+    assert_eq!(swings[4..], [0, 0]);
+    assert_eq!(camera.place(Layer::Playfield, vec2(5, 5)), vec2(5, 5));
+}
+
+#[test_case]
+fn test_peg_motions(_gba: &mut Gba) {
+    use crate::physics::Motion;
+
+    let config = PhysicsConfig {
+        left_wall: 20,
+        up_wall: 20,
+        right_wall: 180,
+        down_wall: 130,
+        moving_radius: 3,
+        static_radius: 3,
+        gravity: 0,
+        repulsion_strength: 3000,
+        object_radius: 4,
+        jitter: 5,
+    };
+    let anchors = [
+        vec2(num!(60), num!(60)),
+        vec2(num!(100), num!(60)),
+        vec2(num!(60), num!(100)),
+    ];
+    let mut positions = anchors;
+    let mut velocities = [vec2(num!(0), num!(0)); 3];
+    velocities[2] = vec2(num!(40), num!(0));
+    let collidable = [true; 3];
+    let force_radius_squared = [num!(25); 3];
+
+    let mut physics =
+        Physics::<3>::new(&positions, &collidable).expect("init failed");
+    let mut rng = RandomNumberGenerator::new();
+    physics.set_motion(0, Motion::Static, anchors[0]);
+    physics.set_motion(
+        1,
+        Motion::Oscillating {
+            offset: vec2(20, 0),
+            period: 60,
+        },
+        anchors[1],
+    );
+    physics.set_motion(2, Motion::Spring { stiffness: 20 }, anchors[2]);
+
+    let mut furthest = num!(0);
+    for frame in 1..=240 {
+        physics
+            .move_from_fields::<1>(
+                &mut positions,
+                &mut velocities,
+                &collidable,
+                &force_radius_squared,
+                num!(1.0 / 60.0),
+                &config,
+                &mut rng,
+            )
+            .expect("step failed");
+
+        assert_eq!(positions[0], anchors[0]);
+        match frame % 60 {
+            0 => assert_eq!(positions[1], anchors[1]),
+            30 => assert_eq!(positions[1].round(), vec2(120, 60)),
+            _ => {}
+        }
+        furthest = furthest.max((positions[2] - anchors[2]).magnitude());
+    }
+
+    // Left alone, the spring peg would drift over 30 pixels away.
+    assert!(furthest < num!(10));
+    assert!((positions[2] - anchors[2]).magnitude() < num!(2));
+}
+
+/// Throws a few pegs at the walls, with jitter driven by `seed`.
+fn bounce_pegs(seed: [u32; 4]) -> [crate::Coordinates; 4] {
+    let config = PhysicsConfig {
+        left_wall: 20,
+        up_wall: 20,
+        right_wall: 180,
+        down_wall: 130,
+        moving_radius: 3,
+        static_radius: 3,
+        gravity: 0,
+        repulsion_strength: 3000,
+        object_radius: 4,
+        jitter: 5,
+    };
+    let mut positions = core::array::from_fn(|i| {
+        vec2(Fixed::new(40 + 30 * i as i32), num!(60))
+    });
+    let mut velocities = core::array::from_fn(|i| {
+        vec2(Fixed::new(if i % 2 == 0 { -45 } else { 45 }), num!(-35))
+    });
+    let collidable = [true; 4];
+    let force_radius_squared = [num!(25); 4];
+
+    let mut physics =
+        Physics::<4>::new(&positions, &collidable).expect("init failed");
+    let mut rng = RandomNumberGenerator::new_with_seed(seed);
+    for _ in 0..120 {
+        physics
+            .move_from_fields::<1>(
+                &mut positions,
+                &mut velocities,
+                &collidable,
+                &force_radius_squared,
+                num!(1.0 / 60.0),
+                &config,
+                &mut rng,
+            )
+            .expect("step failed");
+    }
+    positions
+}
+
+#[test_case]
+fn test_peg_jitter_replays(_gba: &mut Gba) {
+    let first = bounce_pegs([1, 2, 3, 4]);
+    assert_eq!(bounce_pegs([1, 2, 3, 4]), first);
+    assert_ne!(bounce_pegs([5, 6, 7, 8]), first);
+}
+
+#[test_case]
+fn test_grid_survives_crowding(_gba: &mut Gba) {
+    const MAX_PEGS: usize = GameConfig::MAX_PEGS;
+
+    let peg_config = PhysicsConfig {
+        left_wall: GameConfig::WALL_LEFT,
+        up_wall: GameConfig::PHYSICS_UPPER_WALL,
+        right_wall: GameConfig::WALL_RIGHT,
+        down_wall: GameConfig::PHYSICS_LOWER_WALL_PEGS,
+        moving_radius: GameConfig::PEG_RADIUS,
+        static_radius: GameConfig::PEG_RADIUS,
+        gravity: 0,
+        repulsion_strength: GameConfig::PHYSICS_REPULSION_STRENGTH,
+        object_radius: GameConfig::PHYSICS_OBJECT_RADIUS_PEG,
+        jitter: 5,
+    };
+    let ball_config = PhysicsConfig {
+        up_wall: 0,
+        down_wall: GameConfig::PHYSICS_LOWER_WALL_BALL,
+        moving_radius: GameConfig::BALL_RADIUS,
+        gravity: GameConfig::PHYSICS_GRAVITY,
+        repulsion_strength: 0,
+        object_radius: GameConfig::PHYSICS_OBJECT_RADIUS_BALL,
+        jitter: 0,
+        ..peg_config
+    };
+
+    // Like a green peg spawning every peg at once.
+    let spawn = vec2(num!(100), num!(70));
+    let mut rng = RandomNumberGenerator::new();
+    let mut positions = [spawn; MAX_PEGS];
+    let mut velocities = core::array::from_fn(|_| {
+        vec2(
+            Fixed::new(rng.next_i32() % GameConfig::PEG_SPAWN_VELOCITY_RANGE),
+            Fixed::new(rng.next_i32() % GameConfig::PEG_SPAWN_VELOCITY_RANGE),
+        )
+    });
+    let collidable = [true; MAX_PEGS];
+    let force_radius_squared = [num!(100); MAX_PEGS];
+
+    let mut physics = Physics::<MAX_PEGS>::new(&positions, &collidable)
+        .expect("crowded init failed");
+    let mut ball = (vec2(num!(100), num!(10)), vec2(num!(0), num!(0)));
+
+    for _ in 0..180 {
+        physics
+            .move_from_fields::<15>(
+                &mut positions,
+                &mut velocities,
+                &collidable,
+                &force_radius_squared,
+                num!(GameConfig::DELTA_TIME),
+                &peg_config,
+                &mut rng,
+            )
+            .expect("crowded peg step failed");
+        let (position, velocity, _) = physics
+            .move_and_collide(
+                ball.0,
+                ball.1,
+                &positions,
+                &collidable,
+                num!(GameConfig::DELTA_TIME),
+                &[],
+                &ball_config,
+            )
+            .expect("crowded ball step failed");
+        ball = (position, velocity);
+    }
+
+    let still_stacked = positions
+        .iter()
+        .filter(|&&position| (position - spawn).magnitude_squared() < num!(4))
+        .count();
+    assert_eq!(still_stacked, 0);
+}
+
+#[test_case]
+fn test_tall_narrow_grid(_gba: &mut Gba) {
+    use crate::physics::Motion;
+
+    // 10 by 40 cells of 8 pixels: the world is 72 wide and 312 tall.
+    let config = PhysicsConfig {
+        left_wall: 0,
+        up_wall: 0,
+        right_wall: 1000,
+        down_wall: 1000,
+        moving_radius: 3,
+        static_radius: 3,
+        gravity: 0,
+        repulsion_strength: 3000,
+        object_radius: 4,
+        jitter: 0,
+    };
+    let anchor = vec2(num!(40), num!(100));
+    let mut positions = [anchor];
+    let mut velocities = [vec2(num!(0), num!(0))];
+    let collidable = [true];
+
+    let mut physics = Physics::<1, 10, 40, 3>::new(&positions, &collidable)
+        .expect("init failed");
+    physics.set_motion(
+        0,
+        Motion::Oscillating {
+            offset: vec2(100, 400),
+            period: 60,
+        },
+        anchor,
+    );
+
+    let mut rng = RandomNumberGenerator::new();
+    for _ in 0..30 {
+        physics
+            .move_from_fields::<1>(
+                &mut positions,
+                &mut velocities,
+                &collidable,
+                &[num!(25)],
+                num!(1.0 / 60.0),
+                &config,
+                &mut rng,
+            )
+            .expect("step failed");
+    }
+
+    assert_eq!(positions[0], vec2(num!(72), num!(312)));
+}
@@ -0,0 +1,80 @@
+use crate::Coordinates;
+use crate::save::BallKind;
+use agb::display::GraphicsFrame;
+use agb::display::Priority;
+use agb::display::object::{GraphicsMode, Object};
+use agb::fixnum::{num, vec2};
+use agb::include_aseprite;
+
+use super::camera::{Camera, Layer};
+use super::config::GameConfig;
+
+include_aseprite!(
+    mod sprites,
+    "assets/trail.aseprite"
+);
+
+const LENGTH: usize = GameConfig::BALL_TRAIL_LENGTH;
+/// Dot sizes per colour in the sprite sheet, from fresh to faded.
+const FADE_STEPS: usize = 3;
+
+// Fails the build when `trail.aseprite` lacks a fade step for some kind.
+const _: () = {
+    let mut i = 0;
+    while i < BallKind::ALL.len() {
+        let last = (BallKind::ALL[i] as usize + 1) * FADE_STEPS - 1;
+        let _ = sprites::TRAIL.sprite(last);
+        i += 1;
+    }
+};
+
+/// The last few positions of a ball, drawn as shrinking dots in the colour
+/// of its kind. Plain objects without affine matrices, so several trails
+/// stay cheap.
+pub struct Trail {
+    kind: BallKind,
+    positions: [Coordinates; LENGTH],
+    head: usize,
+    len: usize,
+    tick: u32,
+}
+
+impl Trail {
+    pub fn new(kind: BallKind) -> Self {
+        Self {
+            kind,
+            positions: [vec2(num!(0), num!(0)); LENGTH],
+            head: 0,
+            len: 0,
+            tick: 0,
+        }
+    }
+
+    /// Keeps one position every `BALL_TRAIL_SPACING` frames.
+    pub fn record(&mut self, position: Coordinates) {
+        self.tick += 1;
+        if self.tick < GameConfig::BALL_TRAIL_SPACING {
+            return;
+        }
+        self.tick = 0;
+
+        self.positions[self.head] = position;
+        self.head = (self.head + 1) % LENGTH;
+        self.len = (self.len + 1).min(LENGTH);
+    }
+
+    pub fn show(&self, frame: &mut GraphicsFrame, camera: &Camera) {
+        let row = self.kind as usize * FADE_STEPS;
+
+        for age in 0..self.len {
+            let position =
+                self.positions[(self.head + LENGTH - 1 - age) % LENGTH];
+            let step = age * FADE_STEPS / LENGTH;
+            Object::new(sprites::TRAIL.sprite(row + step))
+                .set_pos(camera.place(Layer::Playfield, position.round()))
+                .set_priority(Priority::P2)
+                .set_graphics_mode(GraphicsMode::AlphaBlending)
+                .show(frame);
+        }
+    }
+}
@@ -6,6 +6,7 @@ use agb::input::{Button, ButtonController};
 
 use crate::error::Error;
 use crate::scenes::Scene;
+use crate::scenes::transition::{Kind, Transition};
 
 include_background_gfx!(
     mod background,
@@ -13,7 +14,10 @@ include_background_gfx!(
     BACKGROUND => deduplicate "assets/game_over_screen.aseprite",
 );
 
-pub fn main(gba: &mut agb::Gba) -> Result<Scene, Error> {
+pub fn main(
+    gba: &mut agb::Gba,
+    transition: &mut Transition,
+) -> Result<Scene, Error> {
     let mut gfx = gba.graphics.get();
     let mut input = ButtonController::new();
 
@@ -30,12 +34,17 @@ pub fn main(gba: &mut agb::Gba) -> Result<Scene, Error> {
     loop {
         input.update();
 
-        if input.is_just_pressed(Button::A) {
-            return Ok(Scene::Title);
+        if let Some(scene) = transition.update() {
+            return Ok(scene);
+        }
+
+        if transition.is_idle() && input.is_just_pressed(Button::A) {
+            transition.leave(Kind::FadeToBlack, Scene::Title);
         }
 
         let mut frame = gfx.frame();
-        bg.show(&mut frame);
+        let bg_id = bg.show(&mut frame);
+        transition.show(&mut frame, &[bg_id.into()]);
         frame.commit();
     }
 }
@@ -1,32 +1,83 @@
 use crate::error::Error;
-use crate::level::Level;
+use crate::level::{self, Level};
+use crate::progress::Progress;
 use crate::save::Save;
+use transition::Transition;
 
+mod collection;
 mod drop;
-mod game;
+mod event;
+pub mod game;
 mod game_over;
+mod panel;
+mod stats;
 mod text_box;
 mod title;
+mod transition;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Scene {
     Title,
     Game,
     GameOver,
     Drop,
+    Event,
+    Collection,
+    Stats,
 }
 
 pub fn main(mut gba: agb::Gba, mut save: Save) -> Result<(), Error> {
     let mut state = Scene::Title;
+    let mut transition = Transition::new();
+    let mut progress = Progress::load(&mut gba.save);
 
     loop {
         let level = save.level();
+        let ascension = save.ascension();
+        let before = progress.clone();
+
         state = match state {
-            Scene::Title => title::main(&mut gba)?,
+            Scene::Title => {
+                title::main(&mut gba, &mut save, &progress, &mut transition)?
+            }
             Scene::Game => {
-                game::main(&mut gba, &mut save, Level::new_test_level(level))?
+                let next = game::main(
+                    &mut gba,
+                    &mut save,
+                    Level::new_test_level(level, ascension),
+                    &mut transition,
+                )?;
+                match next {
+                    Scene::Drop if level >= level::FINAL_DEPTH => {
+                        progress.record_level(level);
+                        progress.record_win(&save);
+                        Scene::Title
+                    }
+                    Scene::Drop => {
+                        progress.record_level(level);
+                        next
+                    }
+                    _ => next,
+                }
+            }
+            Scene::GameOver => {
+                progress.record_loss(&save);
+                game_over::main(&mut gba, &mut transition)?
             }
-            Scene::GameOver => game_over::main(&mut gba)?,
-            Scene::Drop => drop::main(&mut gba, &mut save)?,
+            Scene::Drop => drop::main(&mut gba, &mut save, &mut transition)?,
+            Scene::Event => event::main(&mut gba, &mut save, &mut transition)?,
+            Scene::Collection => {
+                collection::main(&mut gba, &progress, &mut transition)?
+            }
+            Scene::Stats => stats::main(&mut gba, &progress, &mut transition)?,
         };
+
+        progress.discover(save.inventory());
+        // Losing the profile is better than freezing mid-run.
+        if progress != before
+            && let Err(err) = progress.store(&mut gba.save)
+        {
+            agb::eprintln!("Could not store progress: {:?}", err);
+        }
     }
 }
@@ -0,0 +1,43 @@
+use agb::display::tiled::{RegularBackground, VRAM_MANAGER};
+use agb::include_background_gfx;
+
+include_background_gfx!(
+    mod background,
+    "000000",
+    BOX => "assets/drop_box.aseprite",
+);
+
+// Panels span the screen, in tiles
+const LEFT: i32 = 1;
+const RIGHT: i32 = 28;
+
+pub fn set_palettes() {
+    VRAM_MANAGER.set_background_palettes(background::PALETTES);
+}
+
+/// Draws the frame of a full width panel, `top` and `height` in tiles.
+pub fn draw(bg: &mut RegularBackground, top: i32, height: i32) {
+    let tiles = &background::BOX.tiles;
+    let [corner, edge, side] =
+        [0, 1, 2].map(|i| background::BOX.tile_settings[i]);
+    let bottom = top + height - 1;
+
+    for y in top..=bottom {
+        for x in LEFT..=RIGHT {
+            let vertical = x == LEFT || x == RIGHT;
+            let horizontal = y == top || y == bottom;
+            let setting = match (vertical, horizontal) {
+                (true, true) => corner,
+                (false, true) => edge,
+                (true, false) => side,
+                (false, false) => continue,
+            };
+
+            bg.set_tile(
+                (x, y),
+                tiles,
+                setting.hflip(x == RIGHT).vflip(y == bottom),
+            );
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use super::text_box::TextBox;
+use agb::display::tiled::{
+    RegularBackground, RegularBackgroundSize, TileFormat,
+};
+use agb::fixnum::{Vector2D, vec2};
+use agb::input::{Button, ButtonController};
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::Error;
+use crate::history::RunRecord;
+use crate::progress::Progress;
+use crate::scenes::Scene;
+use crate::scenes::panel;
+use crate::scenes::transition::{Kind, Transition};
+
+const TEXT_REVEAL_SPEED: usize = 2;
+const SHOT_WIDTH: usize = 7;
+
+// Panels, in tiles
+const HEADER_TOP: i32 = 0;
+const HEADER_ROWS: i32 = 6;
+const BODY_TOP: i32 = 6;
+const BODY_ROWS: i32 = 14;
+
+// In pixels
+const HEADER_POS: Vector2D<i32> = vec2(20, 3);
+const HEADER_WIDTH: i32 = 200;
+const HEADER_HEIGHT: i32 = 42;
+const BODY_POS: Vector2D<i32> = vec2(20, 57);
+const BODY_WIDTH: i32 = 200;
+const BODY_HEIGHT: i32 = 92;
+
+fn totals(progress: &Progress) -> String {
+    let stats = progress.stats();
+    format!(
+        "{{yellow}}Stats{{white}}  B: back\n\
+         {} runs, {} won\n\
+         {} levels cleared, deepest {}",
+        stats.runs, stats.wins, stats.levels_cleared, stats.best_depth
+    )
+}
+
+fn run(index: usize, count: usize, record: &RunRecord) -> String {
+    let mut text = format!(
+        "{{yellow}}Run {}/{count}{{white}}  {}\n\
+         Ascension {}, depth {}\n\
+         Best shot {}, {} pegs hit\n\
+         Seed {:08X}\n\
+         Bag:",
+        index + 1,
+        record.ending.description(),
+        record.ascension.tier(),
+        record.depth,
+        record.best_shot.compact(SHOT_WIDTH),
+        record.pegs_hit,
+        record.seed,
+    );
+    for ball in &record.inventory {
+        let tier = match ball.tier {
+            0 | 1 => "",
+            2 => " II",
+            _ => " III",
+        };
+        text.push_str(&format!(" {}{tier},", ball.kind.name()));
+    }
+    text.pop();
+    text
+}
+
+fn body(progress: &Progress, selected: usize) -> String {
+    let runs = progress.history().runs();
+    match runs.get(selected) {
+        Some(record) => run(selected, runs.len(), record),
+        None => String::from("No runs yet."),
+    }
+}
+
+/// Totals over every run, then the last few runs one at a time.
+pub fn main(
+    gba: &mut agb::Gba,
+    progress: &Progress,
+    transition: &mut Transition,
+) -> Result<Scene, Error> {
+    let mut gfx = gba.graphics.get();
+    let mut input = ButtonController::new();
+
+    crate::reset::reset_input(Button::A, &mut input, &mut gfx);
+
+    panel::set_palettes();
+    let mut bg = RegularBackground::new(
+        agb::display::Priority::P0,
+        RegularBackgroundSize::Background32x32,
+        TileFormat::FourBpp,
+    );
+    panel::draw(&mut bg, HEADER_TOP, HEADER_ROWS);
+    panel::draw(&mut bg, BODY_TOP, BODY_ROWS);
+
+    let mut header = TextBox::new(HEADER_POS, HEADER_WIDTH, HEADER_HEIGHT);
+    header.set_reveal_speed(TEXT_REVEAL_SPEED);
+    header.set_text(&totals(progress));
+
+    let count = progress.history().runs().len();
+    let mut selected: usize = 0;
+    let mut text_box = TextBox::new(BODY_POS, BODY_WIDTH, BODY_HEIGHT);
+    text_box.set_reveal_speed(TEXT_REVEAL_SPEED);
+    text_box.set_text(&body(progress, selected));
+
+    loop {
+        input.update();
+        header.update();
+        text_box.update();
+
+        if let Some(scene) = transition.update() {
+            return Ok(scene);
+        }
+
+        if transition.is_idle() {
+            let previous = selected;
+            if input.is_just_pressed(Button::B) {
+                transition.leave(Kind::FadeToBlack, Scene::Title);
+            } else if input.is_just_pressed(Button::A) {
+                text_box.next_page();
+            } else if input.is_just_pressed(Button::UP) && selected > 0 {
+                selected -= 1;
+            } else if input.is_just_pressed(Button::DOWN)
+                && selected + 1 < count
+            {
+                selected += 1;
+            }
+
+            if selected != previous {
+                text_box.set_text(&body(progress, selected));
+            }
+        }
+
+        let mut frame = gfx.frame();
+        let bg_id = bg.show(&mut frame);
+        header.show(&mut frame);
+        text_box.show(&mut frame);
+        transition.show(&mut frame, &[bg_id.into()]);
+        frame.commit();
+    }
+}
@@ -1,33 +1,173 @@
-use crate::text::{FONT, PALETTE};
+use crate::peg::Kind;
+use crate::text::{self, FONT, PALETTE};
 use agb::display::GraphicsFrame;
-use agb::display::font::{AlignmentKind, Layout, ObjectTextRenderer};
-use agb::display::object::{Object, Size};
-use agb::fixnum::Vector2D;
+use agb::display::font::{
+    AlignmentKind, ChangeColour, Layout, LetterGroup, ObjectTextRenderer, Tag,
+};
+use agb::display::object::{Object, Size, Sprite};
+use agb::fixnum::{Vector2D, vec2};
+use agb::include_aseprite;
+use alloc::string::String;
 use alloc::vec::Vec;
 
+include_aseprite!(
+    mod sprites,
+    "assets/peg.aseprite",
+    "assets/icons.aseprite",
+);
+
 pub const TEXT_LETTER_GROUP_SIZE: i32 = 16;
 
+const ICON_PLACEHOLDER: &str = "oo";
+const ICON_Y_OFFSET: i32 = 3;
+const INDICATOR_BLINK_FRAMES: u32 = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Icon {
+    Peg(Kind),
+    Coin,
+}
+
+impl Icon {
+    const ALL: [Icon; 5] = [
+        Icon::Peg(Kind::Blue),
+        Icon::Peg(Kind::Red),
+        Icon::Peg(Kind::Yellow),
+        Icon::Peg(Kind::Green),
+        Icon::Coin,
+    ];
+
+    fn from_name(name: &str) -> Option<Icon> {
+        match name {
+            "blue" => Some(Icon::Peg(Kind::Blue)),
+            "red" => Some(Icon::Peg(Kind::Red)),
+            "yellow" => Some(Icon::Peg(Kind::Yellow)),
+            "green" => Some(Icon::Peg(Kind::Green)),
+            "coin" => Some(Icon::Coin),
+            _ => None,
+        }
+    }
+
+    fn tag(self) -> Tag {
+        match self {
+            Icon::Peg(Kind::Blue) => Tag::new(0),
+            Icon::Peg(Kind::Red) => Tag::new(1),
+            Icon::Peg(Kind::Yellow) => Tag::new(2),
+            Icon::Peg(Kind::Green) => Tag::new(3),
+            Icon::Coin => Tag::new(4),
+        }
+    }
+
+    fn sprite(self) -> &'static Sprite {
+        match self {
+            Icon::Peg(Kind::Blue) => sprites::BLUE.sprite(0),
+            Icon::Peg(Kind::Red) => sprites::RED.sprite(0),
+            Icon::Peg(Kind::Yellow) => sprites::YELLOW.sprite(0),
+            Icon::Peg(Kind::Green) => sprites::GREEN.sprite(0),
+            Icon::Coin => sprites::COIN.sprite(0),
+        }
+    }
+}
+
+/// How a full page hands over to the next one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Advance {
+    Input,
+    Timed(u32),
+}
+
+/// Expands `{colour}` and `[icon]` markup into the control characters
+/// understood by the font layout. Unknown markup is kept as plain text.
+fn markup(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+
+    while let Some(start) = rest.find(['{', '[']) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let close = if rest.starts_with('{') { '}' } else { ']' };
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+
+        let name = &rest[1..end];
+        let handled = match close {
+            '}' => match text::colour_from_name(name) {
+                Some(colour) => {
+                    out.push(ChangeColour::new(colour).to_char());
+                    true
+                }
+                None => false,
+            },
+            _ => match Icon::from_name(name) {
+                Some(icon) => {
+                    out.push(icon.tag().set());
+                    out.push_str(ICON_PLACEHOLDER);
+                    out.push(icon.tag().unset());
+                    true
+                }
+                None => false,
+            },
+        };
+
+        if !handled {
+            out.push_str(&rest[..=end]);
+        }
+        rest = &rest[end + 1..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
 pub struct TextBox {
     position: Vector2D<i32>,
     width: i32,
+    height: i32,
     layout: Option<Layout>,
+    pending: Option<LetterGroup>,
     letters: Vec<Object>,
+    page: i32,
+    reveal_speed: usize,
+    advance: Advance,
+    waiting: Option<u32>,
+    indicator: Object,
 }
 
 impl TextBox {
-    pub fn new(position: Vector2D<i32>, width: i32) -> Self {
+    pub fn new(position: Vector2D<i32>, width: i32, height: i32) -> Self {
+        let mut indicator = Object::new(sprites::CONTINUE.sprite(0));
+        indicator.set_pos(position + vec2(width - 8, height - 8));
+
         Self {
             position,
             width,
+            height,
             layout: None,
+            pending: None,
             letters: Vec::new(),
+            page: 0,
+            reveal_speed: 1,
+            advance: Advance::Input,
+            waiting: None,
+            indicator,
         }
     }
 
+    /// Number of letter groups revealed per update.
+    pub fn set_reveal_speed(&mut self, groups_per_update: usize) {
+        self.reveal_speed = groups_per_update.max(1);
+    }
+
+    pub fn set_advance(&mut self, advance: Advance) {
+        self.advance = advance;
+    }
+
     pub fn set_text(&mut self, text: &str) {
         self.remove();
         self.layout = Some(Layout::new(
-            text,
+            &markup(text),
             &FONT,
             AlignmentKind::Left,
             TEXT_LETTER_GROUP_SIZE,
@@ -35,23 +175,89 @@ impl TextBox {
         ));
     }
 
-    pub fn update(&mut self) {
-        let render = ObjectTextRenderer::new(PALETTE.into(), Size::S16x16);
-        if let Some(layout) = &mut self.layout
-            && let Some(letters) = layout.next()
+    fn lines_per_page(&self) -> i32 {
+        (self.height / FONT.line_height()).max(1)
+    }
+
+    fn render(&self, group: &LetterGroup) -> Object {
+        let offset = self.position
+            - vec2(0, self.page * self.lines_per_page() * FONT.line_height());
+
+        if let Some(icon) =
+            Icon::ALL.into_iter().find(|icon| group.has_tag(icon.tag()))
         {
-            self.letters.push(render.show(&letters, self.position));
+            let mut object = Object::new(icon.sprite());
+            object.set_pos(offset + group.position() + vec2(0, ICON_Y_OFFSET));
+            return object;
+        }
+
+        ObjectTextRenderer::new(PALETTE.into(), Size::S16x16)
+            .show(group, offset)
+    }
+
+    pub fn update(&mut self) {
+        if let Some(waited) = &mut self.waiting {
+            *waited += 1;
+            if let Advance::Timed(frames) = self.advance
+                && *waited >= frames
+            {
+                self.next_page();
+            }
+            return;
+        }
+
+        let page_end = (self.page + 1) * self.lines_per_page();
+
+        for _ in 0..self.reveal_speed {
+            let group = match self.pending.take() {
+                Some(group) => group,
+                None => match self.layout.as_mut().and_then(|l| l.next()) {
+                    Some(group) => group,
+                    None => return,
+                },
+            };
+
+            if group.line() >= page_end {
+                self.pending = Some(group);
+                self.waiting = Some(0);
+                return;
+            }
+
+            let object = self.render(&group);
+            self.letters.push(object);
         }
     }
 
+    /// Moves to the next page if the current one is full. Returns whether
+    /// the page changed.
+    pub fn next_page(&mut self) -> bool {
+        if self.waiting.take().is_none() {
+            return false;
+        }
+
+        self.letters.clear();
+        self.page += 1;
+        true
+    }
+
     pub fn remove(&mut self) {
         self.layout = None;
+        self.pending = None;
         self.letters.clear();
+        self.page = 0;
+        self.waiting = None;
     }
 
     pub fn show(&mut self, frame: &mut GraphicsFrame) {
         for lg in &self.letters {
             lg.show(frame);
         }
+
+        if let Some(waited) = self.waiting
+            && self.advance == Advance::Input
+            && (waited / INDICATOR_BLINK_FRAMES) % 2 == 0
+        {
+            self.indicator.show(frame);
+        }
     }
 }
@@ -1,11 +1,21 @@
 use agb::display::tiled::{
     RegularBackground, RegularBackgroundSize, TileFormat, VRAM_MANAGER,
 };
+use agb::fixnum::{Vector2D, vec2};
 use agb::include_background_gfx;
 use agb::input::{Button, ButtonController};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
+use crate::ascension::Ascension;
 use crate::error::Error;
+use crate::progress::Progress;
+use crate::save::{Loadout, Save};
 use crate::scenes::Scene;
+use crate::scenes::text_box::TextBox;
+use crate::scenes::transition::{Kind, Transition};
+use crate::text::FONT;
 
 include_background_gfx!(
     mod background,
@@ -13,7 +23,153 @@ include_background_gfx!(
     BACKGROUND => deduplicate "assets/title_screen.aseprite",
 );
 
-pub fn main(gba: &mut agb::Gba) -> Result<Scene, Error> {
+// Between the logo and the bottom of the screen, room for five entries.
+const MENU_POS: Vector2D<i32> = vec2(64, 90);
+const MENU_WIDTH: i32 = 170;
+const MENU_REVEAL_SPEED: usize = 32;
+// Above the logo
+const HINT_POS: Vector2D<i32> = vec2(64, 28);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Entry {
+    Start,
+    Ascension,
+    Loadout,
+    Collection,
+    Stats,
+}
+
+struct Menu {
+    entries: Vec<Entry>,
+    selected: usize,
+    ascension: Ascension,
+    unlocked: Ascension,
+    loadouts: Vec<Loadout>,
+    loadout: usize,
+}
+
+impl Menu {
+    fn new(save: &Save, progress: &Progress) -> Self {
+        let unlocked = progress.unlocked();
+        let loadouts: Vec<Loadout> = Loadout::ALL
+            .into_iter()
+            .filter(|&loadout| progress.has_loadout(loadout))
+            .collect();
+
+        let mut entries = Vec::from([Entry::Start]);
+        if unlocked.tier() > 0 {
+            entries.push(Entry::Ascension);
+        }
+        if loadouts.len() > 1 {
+            entries.push(Entry::Loadout);
+        }
+        entries.push(Entry::Collection);
+        entries.push(Entry::Stats);
+
+        Self {
+            entries,
+            selected: 0,
+            ascension: if save.ascension() <= unlocked {
+                save.ascension()
+            } else {
+                unlocked
+            },
+            unlocked,
+            loadout: loadouts
+                .iter()
+                .position(|&loadout| loadout == save.loadout())
+                .unwrap_or(0),
+            loadouts,
+        }
+    }
+
+    fn entry(&self) -> Entry {
+        self.entries[self.selected]
+    }
+
+    /// Moves the cursor or changes the selected setting. Returns whether
+    /// anything changed.
+    fn navigate(&mut self, input: &ButtonController) -> bool {
+        let (up, down) = (
+            input.is_just_pressed(Button::UP),
+            input.is_just_pressed(Button::DOWN),
+        );
+        let step = if input.is_just_pressed(Button::LEFT) {
+            -1
+        } else if input.is_just_pressed(Button::RIGHT) {
+            1
+        } else {
+            0
+        };
+
+        if up && self.selected > 0 {
+            self.selected -= 1;
+            return true;
+        }
+        if down && self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+            return true;
+        }
+
+        match (self.entry(), step) {
+            (_, 0) => false,
+            (Entry::Ascension, _) => {
+                let tier = (self.ascension.tier() as i32 + step)
+                    .clamp(0, self.unlocked.tier() as i32);
+                let changed = tier != self.ascension.tier() as i32;
+                self.ascension = Ascension::new(tier as u8);
+                changed
+            }
+            (Entry::Loadout, _) => {
+                let count = self.loadouts.len() as i32;
+                self.loadout =
+                    (self.loadout as i32 + step).rem_euclid(count) as usize;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn text(&self) -> String {
+        let mut text = String::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            let line = match entry {
+                Entry::Start => String::from("Start run"),
+                Entry::Ascension => {
+                    format!("< Ascension {} >", self.ascension.tier())
+                }
+                Entry::Loadout => format!(
+                    "< Loadout: {} >",
+                    self.loadouts[self.loadout].name()
+                ),
+                Entry::Collection => String::from("Collection"),
+                Entry::Stats => String::from("Stats"),
+            };
+            if i == self.selected {
+                text.push_str(&format!("{{yellow}}{line}{{white}}\n"));
+            } else {
+                text.push_str(&format!("{line}\n"));
+            }
+        }
+        text.pop();
+        text
+    }
+
+    /// What the selected setting does, shown apart from the entries.
+    fn hint(&self) -> &'static str {
+        match self.entry() {
+            Entry::Ascension => self.ascension.description(),
+            _ => "",
+        }
+    }
+}
+
+pub fn main(
+    gba: &mut agb::Gba,
+    save: &mut Save,
+    progress: &Progress,
+    transition: &mut Transition,
+) -> Result<Scene, Error> {
     let mut gfx = gba.graphics.get();
     let mut input = ButtonController::new();
 
@@ -27,15 +183,59 @@ pub fn main(gba: &mut agb::Gba) -> Result<Scene, Error> {
     );
     bg.fill_with(&background::BACKGROUND);
 
+    let mut menu = Menu::new(save, progress);
+    let line_height = FONT.line_height();
+    let mut text_box = TextBox::new(
+        MENU_POS,
+        MENU_WIDTH,
+        menu.entries.len() as i32 * line_height,
+    );
+    text_box.set_reveal_speed(MENU_REVEAL_SPEED);
+    text_box.set_text(&menu.text());
+    let mut hint = TextBox::new(HINT_POS, MENU_WIDTH, line_height);
+    hint.set_reveal_speed(MENU_REVEAL_SPEED);
+    hint.set_text(menu.hint());
+    // How long the player lingers here seeds the run.
+    let mut frames: u32 = 0;
+
     loop {
+        frames = frames.wrapping_add(1);
         input.update();
+        text_box.update();
+        hint.update();
+
+        if let Some(scene) = transition.update() {
+            return Ok(scene);
+        }
 
-        if input.is_just_pressed(Button::A) {
-            return Ok(Scene::Game);
+        if transition.is_idle() && input.is_just_pressed(Button::A) {
+            match menu.entry() {
+                Entry::Collection => {
+                    transition.leave(Kind::FadeToBlack, Scene::Collection)
+                }
+                Entry::Stats => {
+                    transition.leave(Kind::FadeToBlack, Scene::Stats)
+                }
+                _ => {
+                    *save = Save::new_run(
+                        menu.ascension,
+                        menu.loadouts[menu.loadout],
+                        &progress.pool(),
+                        frames,
+                    );
+                    transition.leave(Kind::HorizontalWipe, Scene::Game);
+                }
+            }
+        } else if transition.is_idle() && menu.navigate(&input) {
+            text_box.set_text(&menu.text());
+            hint.set_text(menu.hint());
         }
 
         let mut frame = gfx.frame();
-        bg.show(&mut frame);
+        let bg_id = bg.show(&mut frame);
+        text_box.show(&mut frame);
+        hint.show(&mut frame);
+        transition.show(&mut frame, &[bg_id.into()]);
         frame.commit();
     }
 }
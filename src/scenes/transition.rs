@@ -0,0 +1,221 @@
+use agb::display::tiled::BackgroundId;
+use agb::display::{GraphicsFrame, HEIGHT, WIDTH, WinIn};
+use agb::fixnum::{Num, Rect, Vector2D, vec2};
+
+use crate::scenes::Scene;
+
+const STEPS: i32 = 16;
+const IRIS_CENTER_X: i32 = WIDTH / 2;
+const IRIS_CENTER_Y: i32 = HEIGHT / 2;
+const IRIS_MAX_RADIUS: i32 = 145;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    FadeToBlack,
+    FadeToWhite,
+    HorizontalWipe,
+    CircleIris,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Phase {
+    Idle,
+    In,
+    Out(Scene),
+}
+
+pub struct Transition {
+    kind: Kind,
+    phase: Phase,
+    step: i32,
+}
+
+impl Transition {
+    pub fn new() -> Self {
+        Self {
+            kind: Kind::FadeToBlack,
+            phase: Phase::Idle,
+            step: 0,
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        matches!(self.phase, Phase::Idle)
+    }
+
+    pub fn is_leaving(&self) -> bool {
+        matches!(self.phase, Phase::Out(_))
+    }
+
+    pub fn leave(&mut self, kind: Kind, scene: Scene) {
+        if self.is_leaving() {
+            return;
+        }
+
+        self.kind = kind;
+        self.phase = Phase::Out(scene);
+        self.step = 0;
+    }
+
+    /// Advances the transition, returning the next scene once the screen is
+    /// fully covered. The next scene then starts by revealing itself.
+    pub fn update(&mut self) -> Option<Scene> {
+        match self.phase {
+            Phase::Idle => None,
+            Phase::In => {
+                self.step += 1;
+                if self.step > STEPS {
+                    self.phase = Phase::Idle;
+                    self.step = 0;
+                }
+                None
+            }
+            Phase::Out(scene) => {
+                self.step += 1;
+                if self.step > STEPS {
+                    self.phase = Phase::In;
+                    self.step = 0;
+                    Some(scene)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn coverage(&self) -> i32 {
+        match self.phase {
+            Phase::Idle => 0,
+            Phase::In => STEPS - self.step,
+            Phase::Out(_) => self.step,
+        }
+        .clamp(0, STEPS)
+    }
+
+    pub fn show(
+        &self,
+        frame: &mut GraphicsFrame,
+        backgrounds: &[BackgroundId],
+    ) {
+        let coverage = self.coverage();
+        if coverage == 0 {
+            return;
+        }
+
+        match self.kind {
+            Kind::FadeToBlack | Kind::FadeToWhite => {
+                Self::show_fade(self.kind, coverage, frame, backgrounds)
+            }
+            Kind::HorizontalWipe => {
+                Self::show_wipe(self.phase, coverage, frame, backgrounds)
+            }
+            Kind::CircleIris => Self::show_iris(coverage, frame, backgrounds),
+        }
+    }
+
+    fn show_fade(
+        kind: Kind,
+        coverage: i32,
+        frame: &mut GraphicsFrame,
+        backgrounds: &[BackgroundId],
+    ) {
+        let amount = Num::<u8, 4>::from_raw(coverage as u8);
+        let blend = frame.blend();
+        let mut effect = if kind == Kind::FadeToWhite {
+            blend.brighten(amount)
+        } else {
+            blend.darken(amount)
+        };
+
+        for &bg in backgrounds {
+            effect.enable_background(bg);
+        }
+        effect.enable_object().enable_backdrop();
+    }
+
+    fn show_wipe(
+        phase: Phase,
+        coverage: i32,
+        frame: &mut GraphicsFrame,
+        backgrounds: &[BackgroundId],
+    ) {
+        let width = WIDTH * coverage / STEPS;
+        let left = match phase {
+            Phase::In => WIDTH - width,
+            _ => 0,
+        };
+
+        let windows = frame.windows();
+        windows
+            .win_in(WinIn::Win0)
+            .set_pos(Rect::new(vec2(left, 0), vec2(width, HEIGHT)));
+
+        let outside = windows.win_out().enable_objects();
+        for &bg in backgrounds {
+            outside.enable_background(bg);
+        }
+    }
+
+    fn show_iris(
+        coverage: i32,
+        frame: &mut GraphicsFrame,
+        backgrounds: &[BackgroundId],
+    ) {
+        let radius = IRIS_MAX_RADIUS * (STEPS - coverage) / STEPS;
+        let windows = frame.windows();
+
+        let inside = windows.win_in(WinIn::Win0).enable_objects();
+        for &bg in backgrounds {
+            inside.enable_background(bg);
+        }
+
+        if radius == 0 {
+            // A closed iris must not leave a DMA running into the next scene
+            inside.set_pos(Rect::new(vec2(0, 0), vec2(0, 0)));
+            return;
+        }
+
+        inside.set_pos(Rect::new(vec2(0, 0), vec2(WIDTH, HEIGHT)));
+        let dma = inside.horizontal_pos_dma();
+        windows.win_out();
+
+        let mut spans = [Vector2D::<u8>::new(0, 0); HEIGHT as usize];
+        for (y, span) in (0..HEIGHT).zip(spans.iter_mut()) {
+            let dy = y - IRIS_CENTER_Y;
+            let remaining = radius * radius - dy * dy;
+            if remaining <= 0 {
+                continue;
+            }
+
+            let dx = isqrt(remaining);
+            let left = (IRIS_CENTER_X - dx).clamp(0, WIDTH) as u8;
+            let right = (IRIS_CENTER_X + dx).clamp(0, WIDTH) as u8;
+            // The window register stores the right edge in the low byte
+            *span = vec2(right, left);
+        }
+
+        agb::dma::HBlankDma::new(dma, &spans).show(frame);
+    }
+}
+
+fn isqrt(value: i32) -> i32 {
+    let mut remainder = value;
+    let mut root = 0;
+    let mut bit = 1 << 30;
+
+    while bit > remainder {
+        bit >>= 2;
+    }
+
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+
+    root
+}
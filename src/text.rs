@@ -1,15 +1,32 @@
 use agb::display::font::Font;
-use agb::{
-    include_font,
-};
-use agb::display::{
-    Palette16, Rgb15,
-};
+use agb::display::{Palette16, Rgb, Rgb15};
+use agb::include_font;
+
+pub const WHITE: u32 = 1;
+pub const RED: u32 = 2;
+pub const BLUE: u32 = 3;
+pub const YELLOW: u32 = 4;
+pub const GREEN: u32 = 5;
 
 pub static PALETTE: &Palette16 = {
     let mut palette = [Rgb15::BLACK; 16];
-    palette[1] = Rgb15::WHITE;
+    palette[WHITE as usize] = Rgb15::WHITE;
+    palette[RED as usize] = Rgb::new(0xff, 0x68, 0xd9).to_rgb15();
+    palette[BLUE as usize] = Rgb::new(0x67, 0x72, 0xa9).to_rgb15();
+    palette[YELLOW as usize] = Rgb::new(0xff, 0xff, 0xaa).to_rgb15();
+    palette[GREEN as usize] = Rgb::new(0x88, 0xff, 0x8e).to_rgb15();
     &Palette16::new(palette)
 };
 
 pub static FONT: Font = include_font!("assets/m3x6.ttf", 16);
+
+pub fn colour_from_name(name: &str) -> Option<u32> {
+    match name {
+        "white" => Some(WHITE),
+        "red" => Some(RED),
+        "blue" => Some(BLUE),
+        "yellow" => Some(YELLOW),
+        "green" => Some(GREEN),
+        _ => None,
+    }
+}